@@ -0,0 +1,112 @@
+//! Simple timing benchmarks for tensor operations.
+//!
+//! Run with `cargo bench`.
+
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::mul;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, InnerProduct, Tensor};
+use diffgeom::typenum::consts::{U0, U1, U4};
+use generic_array::GenericArray;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+struct Test4;
+impl CoordinateSystem for Test4 {
+    type Dimension = U4;
+}
+
+type Rank4 = Tensor<
+    Test4,
+    (
+        CovariantIndex,
+        (CovariantIndex, (CovariantIndex, CovariantIndex)),
+    ),
+>;
+
+type Mixed4 = Tensor<
+    Test4,
+    (
+        CovariantIndex,
+        (ContravariantIndex, (CovariantIndex, CovariantIndex)),
+    ),
+>;
+
+const ITERATIONS: u32 = 100_000;
+
+fn bench<F: FnMut()>(name: &str, mut f: F) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed: Duration = start.elapsed();
+    println!(
+        "{:<40} {:>10.1} ns/iter",
+        name,
+        elapsed.as_nanos() as f64 / f64::from(ITERATIONS)
+    );
+}
+
+fn rank4(offset: f64) -> Rank4 {
+    let coords: Vec<f64> = (0..Rank4::get_num_coords())
+        .map(|i| i as f64 + offset)
+        .collect();
+    Rank4::from_slice(Point::new(GenericArray::default()), &coords)
+}
+
+fn main() {
+    let a = rank4(0.0);
+    let b = rank4(1.0);
+    let c = rank4(2.0);
+
+    bench("a * 2.0 + b - c (naive)", || {
+        let result = mul!(Rank4, f64; black_box(a), 2.0) + black_box(b) - black_box(c);
+        black_box(result);
+    });
+
+    bench("a * 2.0 + b - c (fused)", || {
+        let mut result = black_box(a);
+        result.mul_add_assign(2.0, black_box(&b));
+        result.add_scaled(-1.0, black_box(&c));
+        black_box(result);
+    });
+
+    bench("a += b", || {
+        let mut result = black_box(a);
+        result += black_box(b);
+        black_box(result);
+    });
+
+    bench("a *= 2.0", || {
+        let mut result = black_box(a);
+        result *= black_box(2.0);
+        black_box(result);
+    });
+
+    let mixed = Mixed4::from_slice(*a.get_point(), a.coords_array());
+
+    bench("trace of a rank 4 tensor", || {
+        black_box(black_box(mixed).trace::<U0, U1>());
+    });
+
+    bench("inner product of rank 4 tensors", || {
+        let result =
+            <Mixed4 as InnerProduct<Rank4, U1, U4>>::inner_product(black_box(mixed), black_box(b));
+        black_box(result);
+    });
+
+    let indices: Vec<_> = a.iter_coords().collect();
+
+    bench("indexing all coordinates", || {
+        let a = black_box(&a);
+        let sum: f64 = indices.iter().map(|i| a[Rank4::get_coord(i)]).sum();
+        black_box(sum);
+    });
+
+    bench("get_variance (allocating)", || {
+        black_box(Rank4::get_variance());
+    });
+
+    bench("variance_array (non-allocating)", || {
+        black_box(Rank4::variance_array());
+    });
+}
@@ -0,0 +1,37 @@
+//! Plots the radial field x ∂_x + y ∂_y on the plane, as a quiver plot over a heatmap of its
+//! length, and saves it as a PNG.
+//!
+//! Run with `cargo run --example radial_field --features plotters [output.png]`.
+
+use diffgeom::coordinates::Point;
+use diffgeom::plot::{draw_heatmap, draw_quiver};
+use diffgeom::plotters::prelude::*;
+use diffgeom::systems::Cartesian;
+use diffgeom::tensors::{Scalar, Vector};
+use diffgeom::typenum::consts::U2;
+use generic_array::arr;
+use std::error::Error;
+
+type Plane = Cartesian<U2>;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "radial_field.png".to_owned());
+    let bounds = [(-2.0, 2.0), (-2.0, 2.0)];
+
+    let length = |p: &Point<Plane>| Scalar::<Plane>::new(*p, arr![f64; p[0].hypot(p[1])]);
+    let radial = |p: &Point<Plane>| Vector::<Plane>::new(*p, arr![f64; p[0], p[1]]);
+
+    let root = BitMapBackend::new(&path, (600, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        .margin(20)
+        .build_cartesian_2d(-2.0..2.0, -2.0..2.0)?;
+    draw_heatmap(&mut chart, &length, bounds, [60, 60])?;
+    draw_quiver(&mut chart, &radial, bounds, [12, 12], WHITE.stroke_width(2))?;
+    root.present()?;
+
+    println!("saved the plot to {}", path);
+    Ok(())
+}
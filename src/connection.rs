@@ -0,0 +1,157 @@
+//! Module containing functions related to affine connections, described by their
+//! Christoffel symbols Γ^ρ_{μν} (with the indices in this order).
+//!
+//! The connection of a `MetricSystem` is the Levi-Civita connection, which is torsion-free,
+//! but any field of Christoffel symbols can be used here - e.g. those of the Weitzenböck
+//! connection in teleparallel gravity. Connections are represented by types implementing
+//! the `Connection` trait.
+//!
+//! The first lower index of the Christoffel symbols is the direction of differentiation, i.e.
+//! ∇_μ V^ρ = ∂_μ V^ρ + Γ^ρ_{μν} V^ν. This only matters for connections with torsion.
+
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::field::TensorField;
+use crate::metric::MetricSystem;
+use crate::tensors::{ContravariantIndex, CovariantIndex, Tensor, TwoForm};
+use crate::typenum::consts::{U2, U3, U4};
+use crate::typenum::{Exp, Pow};
+use generic_array::ArrayLength;
+use std::marker::PhantomData;
+
+/// Trait representing an affine connection on the coordinate system `T`.
+pub trait Connection<T>
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+{
+    /// Returns the Christoffel symbols Γ^ρ_{μν} of the connection at a given point.
+    fn christoffel(
+        point: &Point<T>,
+    ) -> Tensor<T, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+
+    /// Returns the torsion tensor T^ρ_{μν} = Γ^ρ_{μν} - Γ^ρ_{νμ} at a given point.
+    fn torsion(
+        point: &Point<T>,
+    ) -> Tensor<T, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        torsion(&Self::christoffel, point)
+    }
+
+    /// Returns the curvature tensor
+    /// R^ρ_{σμν} = ∂_μ Γ^ρ_{νσ} - ∂_ν Γ^ρ_{μσ} + Γ^ρ_{μλ} Γ^λ_{νσ} - Γ^ρ_{νλ} Γ^λ_{μσ},
+    /// with the indices in this order.
+    ///
+    /// The Christoffel symbols are differentiated numerically using the method returned by
+    /// `diff_method`.
+    #[allow(clippy::type_complexity)]
+    fn riemann(
+        point: &Point<T>,
+    ) -> Tensor<
+        T,
+        (
+            ContravariantIndex,
+            (CovariantIndex, (CovariantIndex, CovariantIndex)),
+        ),
+    >
+    where
+        T::Dimension: Pow<U4>,
+        Exp<T::Dimension, U4>: ArrayLength<f64>,
+    {
+        let d = T::dimension();
+        let gamma = Self::christoffel(point);
+
+        // dgamma[[a, b, c, m]] = dΓ^a_{bc}/dx^m
+        let mut dgamma = Tensor::<
+            T,
+            (
+                ContravariantIndex,
+                (CovariantIndex, (CovariantIndex, CovariantIndex)),
+            ),
+        >::zero(point.clone());
+        for m in 0..d {
+            let h = T::diff_step(point, m);
+            for &(offset, weight) in T::diff_method().stencil() {
+                let mut x = point.clone();
+                x[m] += offset * h;
+                let gamma_x = Self::christoffel(&x);
+
+                for coord in gamma_x.iter_coords() {
+                    let index = [coord[0], coord[1], coord[2], m];
+                    dgamma[&index[..]] += weight * gamma_x[&*coord] / h;
+                }
+            }
+        }
+
+        let mut result = Tensor::zero(point.clone());
+        for coord in dgamma.iter_coords() {
+            let (r, s, m, n) = (coord[0], coord[1], coord[2], coord[3]);
+            let mut value = dgamma[&[r, n, s, m][..]] - dgamma[&[r, m, s, n][..]];
+            for l in 0..d {
+                value += gamma[&[r, m, l][..]] * gamma[&[l, n, s][..]]
+                    - gamma[&[r, n, l][..]] * gamma[&[l, m, s][..]];
+            }
+            result[&*coord] = value;
+        }
+
+        result
+    }
+
+    /// Returns the Ricci tensor R_{σν} = R^ρ_{σρν}.
+    fn ricci(point: &Point<T>) -> TwoForm<T>
+    where
+        T::Dimension: Pow<U4>,
+        Exp<T::Dimension, U4>: ArrayLength<f64>,
+    {
+        let riemann = Self::riemann(point);
+        let d = T::dimension();
+        let mut result = TwoForm::<T>::zero(point.clone());
+        for s in 0..d {
+            for n in 0..d {
+                result[&[s, n][..]] = (0..d).map(|r| riemann[&[r, s, r, n][..]]).sum();
+            }
+        }
+        result
+    }
+}
+
+/// The Levi-Civita connection of the metric of the system `M`, i.e. the unique torsion-free
+/// connection preserving the metric.
+pub struct LeviCivita<M>(PhantomData<M>);
+
+impl<M> Connection<M> for LeviCivita<M>
+where
+    M: MetricSystem,
+    M::Dimension: Pow<U2> + Pow<U3>,
+    Exp<M::Dimension, U2>: ArrayLength<f64>,
+    Exp<M::Dimension, U3>: ArrayLength<f64>,
+{
+    fn christoffel(
+        point: &Point<M>,
+    ) -> Tensor<M, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        M::christoffel(point)
+    }
+}
+
+/// Returns the torsion tensor T^ρ_{μν} = Γ^ρ_{μν} - Γ^ρ_{νμ} of the connection with the
+/// Christoffel symbols given by the field `christoffel`, at a given point.
+pub fn torsion<T, F>(
+    christoffel: &F,
+    point: &Point<T>,
+) -> Tensor<T, (ContravariantIndex, (CovariantIndex, CovariantIndex))>
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U3>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+    F: TensorField<T, (ContravariantIndex, (CovariantIndex, CovariantIndex))>,
+{
+    let gamma = christoffel.value_at(point);
+    let mut result =
+        Tensor::<T, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::zero(point.clone());
+
+    for i in result.iter_coords() {
+        result[&*i] = gamma[&*i] - gamma[&[i[0], i[2], i[1]][..]];
+    }
+
+    result
+}
@@ -1,12 +1,43 @@
 //! Module containing basic types representing coordinate systems.
 
-use super::tensors::{ContravariantIndex, CovariantIndex, Matrix, Tensor};
-use crate::typenum::consts::U2;
+use super::tensors::{ContravariantIndex, CovariantIndex, Matrix, Tensor, Vector};
+use crate::typenum::consts::{U1, U2, U3};
 use crate::typenum::uint::Unsigned;
-use crate::typenum::Pow;
+use crate::typenum::{Exp, Pow};
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
 use generic_array::{ArrayLength, GenericArray};
-use std::fmt;
-use std::ops::{Index, IndexMut};
+
+/// The method used for calculating derivatives numerically.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DiffMethod {
+    /// Forward difference: (f(x+h) - f(x)) / h. Cheapest, error of order h.
+    Forward,
+    /// Central difference: (f(x+h) - f(x-h)) / 2h. Error of order h^2.
+    Central,
+    /// Five-point stencil: (f(x-2h) - 8f(x-h) + 8f(x+h) - f(x+2h)) / 12h. Error of order h^4.
+    FivePoint,
+}
+
+impl DiffMethod {
+    /// Returns the stencil of the method as a list of pairs (offset, weight).
+    ///
+    /// The derivative is the sum of weight * f(x + offset * h) over the stencil, divided by h.
+    pub fn stencil(self) -> &'static [(f64, f64)] {
+        match self {
+            DiffMethod::Forward => &[(0.0, -1.0), (1.0, 1.0)],
+            DiffMethod::Central => &[(-1.0, -0.5), (1.0, 0.5)],
+            DiffMethod::FivePoint => &[
+                (-2.0, 1.0 / 12.0),
+                (-1.0, -8.0 / 12.0),
+                (1.0, 8.0 / 12.0),
+                (2.0, -1.0 / 12.0),
+            ],
+        }
+    }
+}
 
 /// `CoordinateSystem` marks a struct (usually a unit struct) as representing a coordinate system.
 pub trait CoordinateSystem: Sized {
@@ -20,10 +51,52 @@ pub trait CoordinateSystem: Sized {
         0.01
     }
 
+    /// Function returning the natural length scale of the given axis at a point, in units
+    /// of the coordinate. The step used for numerical differentiation along the axis is
+    /// `small` multiplied by this scale (see `diff_step`), so that e.g. a radial coordinate
+    /// can advertise r as its scale and get steps proportional to the distance from the
+    /// origin. Returns just 1.0 by default.
+    fn characteristic_scale(_: &Point<Self>, _axis: usize) -> f64 {
+        1.0
+    }
+
+    /// Function returning the step used for numerical differentiation along the given axis
+    /// at a point, which is `small` multiplied by `characteristic_scale`.
+    fn diff_step(p: &Point<Self>, axis: usize) -> f64 {
+        Self::small(p) * Self::characteristic_scale(p, axis)
+    }
+
+    /// Function returning the method used for numerical differentiation in this
+    /// coordinate system. Returns `DiffMethod::Central` by default.
+    fn diff_method() -> DiffMethod {
+        DiffMethod::Central
+    }
+
+    /// Function returning the tolerance used when checking whether two points are the same,
+    /// e.g. before adding tensors defined at them. Coordinates x and y are considered equal if
+    /// |x - y| <= tolerance * max(1, |x|, |y|), which allows combining tensors at points
+    /// calculated via different numerical paths. Returns 1e-12 by default.
+    fn point_tolerance() -> f64 {
+        1e-12
+    }
+
     /// Function returning the dimension
     fn dimension() -> usize {
         Self::Dimension::to_usize()
     }
+
+    /// Function returning the names of the coordinates, e.g. `["t", "r", "theta", "phi"]`,
+    /// which allow accessing tensor components by labels. Returns an empty slice by default,
+    /// meaning that the coordinates are unlabeled.
+    fn coordinate_labels() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Function returning a name identifying the coordinate system, stored e.g. along with
+    /// serialized tensors. Returns the name of the type by default.
+    fn system_name() -> &'static str {
+        core::any::type_name::<Self>()
+    }
 }
 
 /// Struct representing a point on the manifold. The information about the coordinate system
@@ -54,6 +127,24 @@ where
     pub fn coords_array(&self) -> &GenericArray<f64, T::Dimension> {
         &self.x
     }
+
+    /// Checks whether the points are the same up to the tolerance returned by
+    /// `CoordinateSystem::point_tolerance`.
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        let tol = T::point_tolerance();
+        self.x
+            .iter()
+            .zip(other.x.iter())
+            .all(|(x, y)| (x - y).abs() <= tol * x.abs().max(y.abs()).max(1.0))
+    }
+
+    /// Returns the point made of the coordinates along the axes `A`, as a point of the
+    /// corresponding `SubSystem`.
+    pub fn project<A: Axes>(&self) -> Point<SubSystem<T, A>> {
+        let axes = A::axes();
+        assert_eq!(axes.len(), A::Dimension::to_usize());
+        Point::from_slice(&axes.iter().map(|&i| self.x[i]).collect::<Vec<_>>())
+    }
 }
 
 impl<T> Clone for Point<T>
@@ -126,34 +217,149 @@ where
     /// Function calculating a Jacobian at a point - that is, the matrix of derivatives
     /// of the coordinate conversions.
     ///
+    /// The default implementation calculates it numerically, using the method returned
+    /// by `diff_method`.
+    ///
     /// This will be contracted with contravariant indices in the tensor.
     fn jacobian(p: &Point<Self>) -> Matrix<T> {
         let d = Self::dimension();
         let mut result = Matrix::zero(Self::convert_point(p));
-        let h = Self::small(p);
 
         for j in 0..d {
-            let mut x = p.clone();
-            x[j] = x[j] - h;
-            let y1 = Self::convert_point(&x);
+            let h = Self::diff_step(p, j);
+            for &(offset, weight) in Self::diff_method().stencil() {
+                let mut x = p.clone();
+                x[j] += offset * h;
+                let y = Self::convert_point(&x);
 
-            x[j] = x[j] + h * 2.0;
-            let y2 = Self::convert_point(&x);
-
-            for i in 0..d {
-                // calculate dyi/dxj
-                let index = [i, j];
-                result[&index[..]] = (y2[i] - y1[i]) / (2.0 * h);
+                for i in 0..d {
+                    // calculate dyi/dxj
+                    let index = [i, j];
+                    result[&index[..]] += weight * y[i] / h;
+                }
             }
         }
 
         result
     }
 
+    /// The determinant of the Jacobian at a point, i.e. the factor by which the conversion
+    /// scales volumes, as needed for changing variables in integrals: the integral of f over
+    /// a region in `T` equals the integral of f times this determinant (in absolute value)
+    /// over the corresponding region in `Self`.
+    fn jacobian_determinant(p: &Point<Self>) -> f64 {
+        ConversionTo::<T>::jacobian(p).determinant()
+    }
+
     /// The inverse matrix of the Jacobian at a point.
     ///
     /// In conversions, it will be contracted with covariant indices.
+    ///
+    /// If `singular_tolerance` returns a tolerance, the default implementation calculates
+    /// the pseudo-inverse of the Jacobian instead, so that it doesn't panic at coordinate
+    /// singularities. Otherwise it panics if the Jacobian isn't invertible.
     fn inv_jacobian(p: &Point<Self>) -> Tensor<T, (CovariantIndex, ContravariantIndex)> {
-        ConversionTo::<T>::jacobian(p).inverse().unwrap()
+        let jacobian = ConversionTo::<T>::jacobian(p);
+        #[cfg(feature = "std")]
+        if let Some(tol) = <Self as ConversionTo<T>>::singular_tolerance() {
+            return jacobian.pseudo_inverse(tol);
+        }
+        jacobian.inverse().unwrap()
+    }
+
+    /// The relative tolerance used for calculating `inv_jacobian` as a pseudo-inverse (see
+    /// `Tensor::pseudo_inverse`), or `None` (the default) for using the exact inverse.
+    ///
+    /// With a tolerance, conversions degrade gracefully near coordinate singularities, such
+    /// as the poles of spherical coordinates: the directions in which the Jacobian is
+    /// (numerically) singular are dropped. The results there are only approximate - the
+    /// components along these directions are meaningless in the first place.
+    #[cfg(feature = "std")]
+    fn singular_tolerance() -> Option<f64> {
+        None
+    }
+}
+
+/// Trait representing an embedding of a coordinate system in another, usually
+/// higher-dimensional one - for example a surface embedded in space.
+///
+/// Unlike with `ConversionTo`, the dimensions of the systems may differ, so tensors can't be
+/// converted in general. Points can be mapped, and the coordinate basis vectors can be
+/// pushed forward.
+pub trait Embedding<T: CoordinateSystem>: CoordinateSystem
+where
+    T::Dimension: Pow<U1>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+{
+    /// Function mapping a point to the embedding space.
+    fn embed_point(p: &Point<Self>) -> Point<T>;
+
+    /// Function calculating the partial derivatives of the embedding with respect to the
+    /// coordinates of `Self`, as vectors at the embedded point - one for each coordinate.
+    ///
+    /// The default implementation calculates them numerically, using the method returned
+    /// by `diff_method`.
+    fn tangent_vectors(p: &Point<Self>) -> Vec<Vector<T>> {
+        let q = Self::embed_point(p);
+
+        (0..Self::dimension())
+            .map(|j| {
+                let h = Self::diff_step(p, j);
+                let mut result = Vector::<T>::zero(q.clone());
+                for &(offset, weight) in Self::diff_method().stencil() {
+                    let mut x = p.clone();
+                    x[j] += offset * h;
+                    let y = Self::embed_point(&x);
+
+                    for i in 0..T::dimension() {
+                        result[i] += weight * y[i] / h;
+                    }
+                }
+                result
+            })
+            .collect()
+    }
+}
+
+/// Trait describing a subset of the coordinate axes of some system, used to define a
+/// `SubSystem`.
+pub trait Axes {
+    /// The number of the selected axes
+    type Dimension: Unsigned + ArrayLength<f64> + ArrayLength<usize>;
+
+    /// Function returning the indices of the selected axes in the parent system. Their
+    /// number must be equal to `Dimension`.
+    fn axes() -> &'static [usize];
+}
+
+/// The spatial axes of a 4-dimensional spacetime in which the coordinate 0 is time, i.e.
+/// the axes 1, 2 and 3.
+pub struct SpatialAxes;
+
+impl Axes for SpatialAxes {
+    type Dimension = U3;
+
+    fn axes() -> &'static [usize] {
+        &[1, 2, 3]
+    }
+}
+
+/// The coordinate system made of the axes `A` of the system `T` - for example the spatial
+/// coordinates of a spacetime.
+///
+/// Tensors can be projected into a subsystem with `Tensor::project`, which just keeps the
+/// components along the selected axes. This corresponds to restricting the tensor to the
+/// slice in which the other coordinates are constant.
+pub struct SubSystem<T: CoordinateSystem, A: Axes>(PhantomData<(T, A)>);
+
+impl<T, A> CoordinateSystem for SubSystem<T, A>
+where
+    T: CoordinateSystem,
+    A: Axes,
+{
+    type Dimension = A::Dimension;
+
+    fn diff_method() -> DiffMethod {
+        T::diff_method()
     }
 }
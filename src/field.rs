@@ -0,0 +1,405 @@
+//! Module containing definitions of tensor fields.
+//!
+//! A tensor field assigns a tensor to every point of the manifold. Any function (or closure)
+//! taking a point and returning a tensor at that point is a tensor field. To use arithmetic
+//! operators on fields, they have to be wrapped in `Field`.
+
+use crate::connection::{Connection, LeviCivita};
+use crate::coordinates::{ConversionTo, CoordinateSystem, Point};
+use crate::metric::MetricSystem;
+use crate::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, IndexType, Tensor, TwoForm, Variance, Vector,
+};
+use crate::typenum::consts::{U0, U1, U2, U3};
+use crate::typenum::{Exp, Pow, Same};
+use generic_array::ArrayLength;
+use std::ops::Mul;
+
+/// Trait representing a tensor field of variance `V` on the coordinate system `T`.
+pub trait TensorField<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Returns the value of the field at a given point.
+    fn value_at(&self, point: &Point<T>) -> Tensor<T, V>;
+}
+
+impl<T, V, F> TensorField<T, V> for F
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    F: Fn(&Point<T>) -> Tensor<T, V>,
+{
+    fn value_at(&self, point: &Point<T>) -> Tensor<T, V> {
+        self(point)
+    }
+}
+
+/// A wrapper around a tensor field enabling the use of arithmetic operators on it.
+///
+/// For example, `Field(scalar_field) * Field(tensor_field)` is a field with the values of
+/// `tensor_field` multiplied pointwise by those of `scalar_field`.
+pub struct Field<F>(pub F);
+
+impl<T, V, F> TensorField<T, V> for Field<F>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    F: TensorField<T, V>,
+{
+    fn value_at(&self, point: &Point<T>) -> Tensor<T, V> {
+        self.0.value_at(point)
+    }
+}
+
+/// A tensor field multiplied pointwise by a scalar field, created by multiplying `Field`s.
+pub struct ScaledField<S, F> {
+    scalar: S,
+    field: F,
+}
+
+impl<T, V, S, F> TensorField<T, V> for ScaledField<S, F>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank> + Pow<U0>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, U0>: ArrayLength<f64>,
+    S: TensorField<T, ()>,
+    F: TensorField<T, V>,
+{
+    fn value_at(&self, point: &Point<T>) -> Tensor<T, V> {
+        self.field.value_at(point) * *self.scalar.value_at(point)
+    }
+}
+
+impl<S, F> Mul<Field<F>> for Field<S> {
+    type Output = Field<ScaledField<S, F>>;
+
+    fn mul(self, rhs: Field<F>) -> Self::Output {
+        Field(ScaledField {
+            scalar: self.0,
+            field: rhs.0,
+        })
+    }
+}
+
+/// Returns the gradient of a scalar field at a given point, i.e. the covector of its partial
+/// derivatives ∂_i f.
+///
+/// The derivatives are calculated numerically using the method returned by `diff_method`.
+pub fn gradient<T, F>(field: &F, point: &Point<T>) -> Covector<T>
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U0> + Pow<U1>,
+    Exp<T::Dimension, U0>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    F: TensorField<T, ()>,
+{
+    let mut result = Covector::<T>::zero(point.clone());
+
+    for i in 0..T::dimension() {
+        let h = T::diff_step(point, i);
+        for &(offset, weight) in T::diff_method().stencil() {
+            let mut x = point.clone();
+            x[i] += offset * h;
+            result[i] += weight * *field.value_at(&x) / h;
+        }
+    }
+
+    result
+}
+
+/// Returns the Hessian of a scalar field at a given point in the coordinate sense, i.e. the
+/// tensor of its second partial derivatives ∂_i ∂_j f.
+///
+/// This is only a tensor in flat coordinates; see `covariant_hessian` for the version taking
+/// the metric into account. The derivatives are calculated numerically using the method
+/// returned by `diff_method`.
+pub fn hessian<T, F>(field: &F, point: &Point<T>) -> TwoForm<T>
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U0> + Pow<U2>,
+    Exp<T::Dimension, U0>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    F: TensorField<T, ()>,
+{
+    let stencil = T::diff_method().stencil();
+    let mut result = TwoForm::<T>::zero(point.clone());
+
+    for i in 0..T::dimension() {
+        for j in 0..T::dimension() {
+            let (h_i, h_j) = (T::diff_step(point, i), T::diff_step(point, j));
+            let mut value = 0.0;
+            for &(offset1, weight1) in stencil {
+                for &(offset2, weight2) in stencil {
+                    let mut x = point.clone();
+                    x[i] += offset1 * h_i;
+                    x[j] += offset2 * h_j;
+                    value += weight1 * weight2 * *field.value_at(&x);
+                }
+            }
+            result[&[i, j][..]] = value / (h_i * h_j);
+        }
+    }
+
+    result
+}
+
+/// Returns the covariant Hessian of a scalar field at a given point, i.e. the tensor
+/// ∇_i ∇_j f = ∂_i ∂_j f - Γ^k_ij ∂_k f, with respect to the Levi-Civita connection of the
+/// metric. See `covariant_hessian_with` for other connections.
+pub fn covariant_hessian<T, F>(field: &F, point: &Point<T>) -> TwoForm<T>
+where
+    T: MetricSystem,
+    T::Dimension: Pow<U0> + Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, U0>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+    F: TensorField<T, ()>,
+{
+    covariant_hessian_with::<LeviCivita<T>, T, F>(field, point)
+}
+
+/// Returns the covariant Hessian ∇_i ∇_j f = ∂_i ∂_j f - Γ^k_ij ∂_k f of a scalar field at a
+/// given point, with respect to the connection `C`.
+pub fn covariant_hessian_with<C, T, F>(field: &F, point: &Point<T>) -> TwoForm<T>
+where
+    C: Connection<T>,
+    T: CoordinateSystem,
+    T::Dimension: Pow<U0> + Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, U0>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+    F: TensorField<T, ()>,
+{
+    let mut result = hessian(field, point);
+    let grad = gradient(field, point);
+    let gamma = C::christoffel(point);
+
+    for i in 0..T::dimension() {
+        for j in 0..T::dimension() {
+            for k in 0..T::dimension() {
+                result[&[i, j][..]] -= gamma[&[k, i, j][..]] * grad[k];
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns the directional derivative V^μ ∂_μ T of a tensor field along a vector, at the point
+/// at which the vector is defined.
+///
+/// The derivative is calculated numerically along the line through the point in the direction
+/// of the vector, using the method returned by `diff_method`.
+pub fn directional_derivative<T, V, F>(field: &F, direction: &Vector<T>) -> Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank> + Pow<U1>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    F: TensorField<T, V>,
+{
+    let point = direction.get_point();
+    let h = T::small(point);
+    let mut result = Tensor::<T, V>::zero(point.clone());
+
+    for &(offset, weight) in T::diff_method().stencil() {
+        let mut x = point.clone();
+        for i in 0..T::dimension() {
+            x[i] += offset * h * direction[i];
+        }
+        let mut value = field.value_at(&x);
+        value.set_point(point.clone());
+        result.add_scaled(weight / h, &value);
+    }
+
+    result
+}
+
+/// Returns the Lie derivative of a tensor field along a vector field X at a given point.
+///
+/// Each contravariant index a contributes a term -(∂_λ X^a) T^{..λ..}, and each covariant
+/// index b a term +(∂_b X^λ) T_{..λ..}, in addition to the directional derivative
+/// X^μ ∂_μ T. The derivatives are calculated numerically using the method returned by
+/// `diff_method`.
+pub fn lie_derivative<T, V, F, X>(field: &F, vector_field: &X, point: &Point<T>) -> Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    V::Rank: ArrayLength<usize>,
+    T::Dimension: Pow<V::Rank> + Pow<U1>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    F: TensorField<T, V>,
+    X: TensorField<T, ContravariantIndex>,
+{
+    let d = T::dimension();
+    let x = vector_field.value_at(point);
+    let value = field.value_at(point);
+    let mut result = directional_derivative(field, &x);
+
+    // scalars have no index terms
+    let variance = V::variance();
+    if variance.is_empty() {
+        return result;
+    }
+
+    // dx[a * d + m] = ∂_m X^a
+    let mut dx = vec![0.0; d * d];
+    for m in 0..d {
+        let h = T::diff_step(point, m);
+        for &(offset, weight) in T::diff_method().stencil() {
+            let mut y = point.clone();
+            y[m] += offset * h;
+            let x_y = vector_field.value_at(&y);
+            for a in 0..d {
+                dx[a * d + m] += weight * x_y[a] / h;
+            }
+        }
+    }
+
+    for coord in value.iter_coords() {
+        let mut idx = coord.clone();
+        for (k, index_type) in variance.iter().enumerate() {
+            for l in 0..d {
+                idx[k] = l;
+                result[&*coord] += match *index_type {
+                    IndexType::Contravariant => -dx[coord[k] * d + l] * value[&*idx],
+                    IndexType::Covariant => dx[l * d + coord[k]] * value[&*idx],
+                };
+            }
+            idx[k] = coord[k];
+        }
+    }
+
+    result
+}
+
+/// Checks whether a vector field is a Killing vector field of a metric at a given point, i.e.
+/// whether the Lie derivative of the metric along it vanishes there, with every coordinate
+/// compared up to the tolerance `tol`.
+pub fn is_killing<T, X, G>(vector_field: &X, metric: &G, point: &Point<T>, tol: f64) -> bool
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U1> + Pow<U2>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    X: TensorField<T, ContravariantIndex>,
+    G: TensorField<T, (CovariantIndex, CovariantIndex)>,
+{
+    lie_derivative(metric, vector_field, point)
+        .coords_array()
+        .iter()
+        .all(|x| x.abs() <= tol)
+}
+
+/// Samples a single component of a tensor field over a regular grid, e.g. for plotting.
+///
+/// `bounds` contains the range (min, max) of each coordinate and `resolution` the number of
+/// grid points [n_0, …, n_{d-1}] along each of them, with both ends of the range included.
+/// `component` is the index of the component in the internal array of the tensor (see
+/// `Tensor::get_coord`), so it is less than dim^rank.
+///
+/// The result is a flat array of shape [n_0, …, n_{d-1}] in row-major order: the value at
+/// the grid point (i_0, …, i_{d-1}) is at position (…(i_0 n_1 + i_1) n_2 + …) n_{d-1} + i_{d-1},
+/// i.e. the last coordinate changes the most often. With the `ndarray` feature,
+/// `sample_field_to_ndarray` returns it as an `ArrayD` of this shape.
+pub fn sample_field<T, V, F>(
+    field: &F,
+    bounds: &[(f64, f64)],
+    resolution: &[usize],
+    component: usize,
+) -> Vec<f64>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    F: TensorField<T, V>,
+{
+    let d = T::dimension();
+    assert_eq!(bounds.len(), d);
+    assert_eq!(resolution.len(), d);
+
+    let total = resolution.iter().product();
+    let mut result = Vec::with_capacity(total);
+    let mut x = vec![0.0; d];
+
+    for k in 0..total {
+        let mut rest = k;
+        for i in (0..d).rev() {
+            let (min, max) = bounds[i];
+            let j = rest % resolution[i];
+            rest /= resolution[i];
+            x[i] = if resolution[i] > 1 {
+                min + (max - min) * j as f64 / (resolution[i] - 1) as f64
+            } else {
+                min
+            };
+        }
+        result.push(field.value_at(&Point::from_slice(&x))[component]);
+    }
+
+    result
+}
+
+/// Samples a single component of a tensor field over a regular grid like `sample_field`, and
+/// returns the values as an array of shape [n_0, …, n_{d-1}], where n_i = `resolution[i]`.
+#[cfg(feature = "ndarray")]
+pub fn sample_field_to_ndarray<T, V, F>(
+    field: &F,
+    bounds: &[(f64, f64)],
+    resolution: &[usize],
+    component: usize,
+) -> ndarray::ArrayD<f64>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    F: TensorField<T, V>,
+{
+    let samples = sample_field(field, bounds, resolution, component);
+    // there is exactly one sample for every element of the array
+    ndarray::ArrayD::from_shape_vec(resolution, samples).unwrap()
+}
+
+/// Converts a tensor field on the system `T` to a field on `T2`.
+///
+/// The returned closure maps each point back to `T`, evaluates the field there and converts
+/// the value with `Tensor::convert`. Nothing is cached - the conversion happens on every
+/// evaluation.
+pub fn convert_field<T, T2, V, F>(field: F) -> impl Fn(&Point<T2>) -> Tensor<T2, V>
+where
+    T: CoordinateSystem + ConversionTo<T2> + 'static,
+    T2: CoordinateSystem + ConversionTo<T> + 'static,
+    V: Variance,
+    V::Rank: ArrayLength<usize> + ArrayLength<IndexType>,
+    T::Dimension: Pow<V::Rank> + Pow<U2>,
+    T2::Dimension: Pow<V::Rank> + Pow<U2> + Same<T::Dimension>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T2::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T2::Dimension, U2>: ArrayLength<f64>,
+    F: TensorField<T, V>,
+{
+    move |point: &Point<T2>| {
+        let value = field.value_at(&<T2 as ConversionTo<T>>::convert_point(point));
+        let mut result = value.convert::<T2>();
+        // avoid the rounding errors of converting the point back and forth
+        result.set_point(point.clone());
+        result
+    }
+}
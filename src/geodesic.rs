@@ -0,0 +1,274 @@
+//! Module containing integrators of the geodesic equation and related equations.
+//!
+//! A geodesic is represented by its tangent vectors: a `Vector` carries the point at which
+//! it is defined, so it describes both the position and the velocity of a particle.
+//!
+//! The functions use the Levi-Civita connection of the metric, and those with the `_with`
+//! suffix any `Connection` - for which the "geodesics" are the autoparallel curves.
+
+use crate::connection::{Connection, LeviCivita};
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::metric::MetricSystem;
+use crate::tensors::{IndexType, Tensor, Variance, Vector};
+use crate::typenum::consts::{U1, U2, U3, U4};
+use crate::typenum::{Exp, Pow, Unsigned};
+use generic_array::ArrayLength;
+
+// Performs a single step of the classical Runge-Kutta method
+fn rk4<F: Fn(&[f64]) -> Vec<f64>>(state: &[f64], step: f64, f: F) -> Vec<f64> {
+    let shifted = |k: &[f64], factor: f64| -> Vec<f64> {
+        state
+            .iter()
+            .zip(k)
+            .map(|(x, dx)| x + factor * step * dx)
+            .collect()
+    };
+
+    let k1 = f(state);
+    let k2 = f(&shifted(&k1, 0.5));
+    let k3 = f(&shifted(&k2, 0.5));
+    let k4 = f(&shifted(&k3, 1.0));
+
+    (0..state.len())
+        .map(|i| state[i] + step / 6.0 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]))
+        .collect()
+}
+
+// Calculates the derivatives of the position and velocity along a geodesic:
+// dx^a/dτ = u^a, du^a/dτ = -Γ^a_bc u^b u^c
+fn geodesic_rhs<C, T>(state: &[f64]) -> Vec<f64>
+where
+    C: Connection<T>,
+    T: CoordinateSystem,
+    T::Dimension: Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+{
+    let d = T::dimension();
+    let (x, u) = state.split_at(d);
+    let gamma = C::christoffel(&Point::from_slice(x));
+
+    let mut result = u.to_vec();
+    for a in 0..d {
+        let mut acc = 0.0;
+        for b in 0..d {
+            for c in 0..d {
+                acc -= gamma[&[a, b, c][..]] * u[b] * u[c];
+            }
+        }
+        result.push(acc);
+    }
+    result
+}
+
+/// Integrates the geodesic equation d²x^a/dτ² = -Γ^a_bc dx^b/dτ dx^c/dτ, starting with the
+/// given velocity at the point at which it is defined.
+///
+/// Performs `steps` steps of length `step` of the classical Runge-Kutta method and returns
+/// the velocities at all the points along the way, including the initial one.
+pub fn integrate_geodesic<T>(initial: &Vector<T>, step: f64, steps: usize) -> Vec<Vector<T>>
+where
+    T: MetricSystem,
+    T::Dimension: Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+{
+    integrate_geodesic_with::<LeviCivita<T>, T>(initial, step, steps)
+}
+
+/// Integrates the autoparallel equation d²x^a/dτ² = -Γ^a_bc dx^b/dτ dx^c/dτ of the connection
+/// `C` in the same way as `integrate_geodesic`.
+pub fn integrate_geodesic_with<C, T>(initial: &Vector<T>, step: f64, steps: usize) -> Vec<Vector<T>>
+where
+    C: Connection<T>,
+    T: CoordinateSystem,
+    T::Dimension: Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+{
+    let d = T::dimension();
+    let mut state: Vec<f64> = initial.get_point().coords_array().to_vec();
+    state.extend_from_slice(initial.coords_array());
+
+    let mut result = vec![initial.clone()];
+    for _ in 0..steps {
+        state = rk4(&state, step, geodesic_rhs::<C, T>);
+        result.push(Vector::<T>::from_slice(
+            Point::from_slice(&state[..d]),
+            &state[d..],
+        ));
+    }
+    result
+}
+
+/// Integrates the geodesic deviation (Jacobi) equation D²ξ/dτ² = -R(ξ, u)u along a geodesic,
+/// starting with the velocity `initial` and the separation `separation` from a neighbouring
+/// geodesic. The neighbouring geodesic is assumed to start parallel, i.e. Dξ/dτ = 0 initially.
+///
+/// The geodesic is integrated the same way as in `integrate_geodesic`. Returns the pairs of
+/// velocities and separation vectors at all the points along the way, including the initial
+/// one.
+///
+/// There is no version of this for general connections, since the equation above only holds
+/// without torsion.
+pub fn integrate_geodesic_deviation<T>(
+    initial: &Vector<T>,
+    separation: &Vector<T>,
+    step: f64,
+    steps: usize,
+) -> Vec<(Vector<T>, Vector<T>)>
+where
+    T: MetricSystem,
+    T::Dimension: Pow<U1> + Pow<U2> + Pow<U3> + Pow<U4> + Unsigned,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+    Exp<T::Dimension, U4>: ArrayLength<f64>,
+{
+    assert!(initial.get_point().approx_eq(separation.get_point()));
+    let d = T::dimension();
+
+    // the state consists of x, u, ξ and η = Dξ/dτ, which evolve as:
+    // dξ^a/dτ = η^a - Γ^a_bc u^b ξ^c
+    // dη^a/dτ = R^a_bcd u^b u^c ξ^d - Γ^a_bc u^b η^c
+    let rhs = |state: &[f64]| -> Vec<f64> {
+        let mut result = geodesic_rhs::<LeviCivita<T>, T>(&state[..2 * d]);
+        let point = Point::from_slice(&state[..d]);
+        let u = &state[d..2 * d];
+        let xi = &state[2 * d..3 * d];
+        let eta = &state[3 * d..];
+        let gamma = T::christoffel(&point);
+        let riemann = T::riemann(&point);
+
+        let mut d_xi = eta.to_vec();
+        let mut d_eta = vec![0.0; d];
+        for a in 0..d {
+            for b in 0..d {
+                for c in 0..d {
+                    let g = gamma[&[a, b, c][..]];
+                    d_xi[a] -= g * u[b] * xi[c];
+                    d_eta[a] -= g * u[b] * eta[c];
+                    for e in 0..d {
+                        d_eta[a] += riemann[&[a, b, c, e][..]] * u[b] * u[c] * xi[e];
+                    }
+                }
+            }
+        }
+        result.extend(d_xi);
+        result.extend(d_eta);
+        result
+    };
+
+    let mut state: Vec<f64> = initial.get_point().coords_array().to_vec();
+    state.extend_from_slice(initial.coords_array());
+    state.extend_from_slice(separation.coords_array());
+    state.extend(vec![0.0; d]);
+
+    let mut result = vec![(initial.clone(), separation.clone())];
+    for _ in 0..steps {
+        state = rk4(&state, step, rhs);
+        let point = Point::from_slice(&state[..d]);
+        result.push((
+            Vector::<T>::from_slice(point.clone(), &state[d..2 * d]),
+            Vector::<T>::from_slice(point, &state[2 * d..3 * d]),
+        ));
+    }
+    result
+}
+
+/// Parallel transports a tensor from the point at which it is defined to `target`, along the
+/// straight line between them in coordinates.
+///
+/// The components satisfy DT/dλ = 0 along the line x(λ) = p + λ(q - p), λ in [0, 1], i.e.
+/// dT/dλ = -Γ^a_bc (q - p)^b T^c for every contravariant index and
+/// dT/dλ = Γ^c_ba (q - p)^b T_c for every covariant index. The equation is integrated with
+/// `steps` steps of the classical Runge-Kutta method.
+///
+/// Note that in a curved space the result depends on the path, so it is specific to the
+/// coordinate line.
+///
+/// The tensor is transported with the Levi-Civita connection of the metric; see
+/// `parallel_transport_with` for other connections.
+pub fn parallel_transport<T, V>(
+    tensor: &Tensor<T, V>,
+    target: &Point<T>,
+    steps: usize,
+) -> Tensor<T, V>
+where
+    T: MetricSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank> + Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+{
+    parallel_transport_with::<LeviCivita<T>, T, V>(tensor, target, steps)
+}
+
+/// Parallel transports a tensor from the point at which it is defined to `target` with the
+/// connection `C`, in the same way as `parallel_transport`.
+pub fn parallel_transport_with<C, T, V>(
+    tensor: &Tensor<T, V>,
+    target: &Point<T>,
+    steps: usize,
+) -> Tensor<T, V>
+where
+    C: Connection<T>,
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank> + Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+{
+    let d = T::dimension();
+    let variance = V::variance();
+    let start = tensor.get_point().clone();
+    let direction: Vec<f64> = (0..d).map(|i| target[i] - start[i]).collect();
+
+    // the state consists of λ and the components of the tensor
+    let rhs = |state: &[f64]| -> Vec<f64> {
+        let lambda = state[0];
+        let x = &state[1..];
+        let point: Point<T> = Point::from_slice(
+            &(0..d)
+                .map(|i| start[i] + lambda * direction[i])
+                .collect::<Vec<_>>(),
+        );
+        let gamma = C::christoffel(&point);
+
+        let mut result = vec![0.0; x.len() + 1];
+        result[0] = 1.0;
+        for (k, dx) in result[1..].iter_mut().enumerate() {
+            let mut stride = x.len();
+            for v in &variance {
+                stride /= d;
+                let a = k / stride % d;
+                let base = k - a * stride;
+                for b in 0..d {
+                    for c in 0..d {
+                        *dx += match *v {
+                            IndexType::Contravariant => -gamma[&[a, b, c][..]],
+                            IndexType::Covariant => gamma[&[c, b, a][..]],
+                        } * direction[b]
+                            * x[base + c * stride];
+                    }
+                }
+            }
+        }
+        result
+    };
+
+    let mut state = vec![0.0];
+    state.extend_from_slice(tensor.coords_array());
+    for _ in 0..steps {
+        state = rk4(&state, 1.0 / steps as f64, rhs);
+    }
+
+    Tensor::<T, V>::from_slice(target.clone(), &state[1..])
+}
@@ -0,0 +1,65 @@
+//! Module containing functions interpolating tensors defined at different points, e.g.
+//! for visualizing fields between the nodes of a grid.
+
+use crate::coordinates::Point;
+use crate::geodesic::parallel_transport;
+use crate::metric::MetricSystem;
+use crate::tensors::{Tensor, Variance};
+use crate::typenum::consts::{U1, U2, U3};
+use crate::typenum::{Exp, Pow};
+use generic_array::ArrayLength;
+
+/// The method of interpolating tensors defined at different points.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Interpolation {
+    /// The components are interpolated directly, as if both tensors were defined at the
+    /// interpolated point. This is cheap, but depends on the coordinates: it is only
+    /// correct if the basis doesn't change between the points, e.g. in Cartesian
+    /// coordinates in a flat space.
+    Naive,
+    /// Both tensors are first parallel transported to the interpolated point along the
+    /// coordinate line, with the given number of steps (see
+    /// `geodesic::parallel_transport`), and then their components are interpolated. This
+    /// accounts for the change of the basis and for the curvature of the space.
+    Transported { steps: usize },
+}
+
+/// Interpolates linearly between the tensors `a` and `b` defined at different points.
+///
+/// The result is defined at the point (1 - t)p + tq, where p and q are the points of `a`
+/// and `b`, and its components are (1 - t)a + tb, after transporting the tensors there if
+/// requested by `method`.
+pub fn interpolate<T, V>(
+    a: &Tensor<T, V>,
+    b: &Tensor<T, V>,
+    t: f64,
+    method: Interpolation,
+) -> Tensor<T, V>
+where
+    T: MetricSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank> + Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+{
+    let (p, q) = (a.get_point(), b.get_point());
+    let point: Point<T> = Point::from_slice(
+        &(0..T::dimension())
+            .map(|i| (1.0 - t) * p[i] + t * q[i])
+            .collect::<Vec<_>>(),
+    );
+
+    let (mut a, mut b) = match method {
+        Interpolation::Naive => (a.clone(), b.clone()),
+        Interpolation::Transported { steps } => (
+            parallel_transport(a, &point, steps),
+            parallel_transport(b, &point, steps),
+        ),
+    };
+    a.set_point(point.clone());
+    b.set_point(point);
+
+    a * (1.0 - t) + b * t
+}
@@ -66,12 +66,45 @@ fn main() {
 }
 ```
 */
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+// The dylib crate type needs a panic handler and a global allocator, which only std provides on
+// stable Rust - so it is still linked on targets that have an operating system, even though the
+// crate itself doesn't use it without the `std` feature. On bare-metal targets only the rlib is
+// built.
+#[cfg(all(not(feature = "std"), not(target_os = "none")))]
+extern crate std as _;
+
+extern crate alloc;
 pub extern crate generic_array;
 pub use generic_array::typenum;
-
+#[cfg(feature = "ndarray")]
+pub extern crate ndarray;
+#[cfg(feature = "complex")]
+pub extern crate num_complex;
+#[cfg(feature = "plotters")]
+pub extern crate plotters;
+#[cfg(feature = "rayon")]
+pub extern crate rayon;
+
+#[cfg(feature = "std")]
+pub mod connection;
 pub mod coordinates;
+#[cfg(feature = "std")]
+pub mod field;
+#[cfg(feature = "std")]
+pub mod geodesic;
+#[cfg(feature = "std")]
+pub mod interpolation;
 pub mod macros;
+#[cfg(feature = "std")]
 pub mod metric;
+#[cfg(feature = "plotters")]
+pub mod plot;
+#[cfg(feature = "std")]
+pub mod surface;
+#[cfg(feature = "std")]
+pub mod systems;
 pub mod tensors;
 
 #[cfg(test)]
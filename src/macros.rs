@@ -1,7 +1,7 @@
 #[macro_export]
 macro_rules! mul {
     ($T1: ty, $T2: ty; $op1: expr, $op2: expr) => {{
-        use std::ops::Mul;
+        use core::ops::Mul;
         <$T1 as Mul<$T2>>::mul($op1, $op2)
     }};
 }
@@ -13,3 +13,71 @@ macro_rules! inner {
         <$T1 as InnerProduct<$T2, $I1, $I2>>::inner_product($op1, $op2)
     }};
 }
+
+/// Declares a unit struct implementing `CoordinateSystem` with the given dimension, and
+/// optionally `MetricSystem` with the given metric, which is a closure taking
+/// `&Point<Name>` and returning `TwoForm<Name>`.
+///
+/// For example, `coordinate_system!(pub Polar, U2, metric = |p| ...)` declares a
+/// two-dimensional system with a metric.
+#[macro_export]
+macro_rules! coordinate_system {
+    ($(#[$attr: meta])* $vis: vis $name: ident, $dim: ty, metric = $metric: expr) => {
+        $crate::coordinate_system!($(#[$attr])* $vis $name, $dim);
+
+        impl $crate::metric::MetricSystem for $name {
+            fn g(p: &$crate::coordinates::Point<Self>) -> $crate::tensors::TwoForm<Self> {
+                ($metric)(p)
+            }
+        }
+    };
+    ($(#[$attr: meta])* $vis: vis $name: ident, $dim: ty) => {
+        $(#[$attr])*
+        $vis struct $name;
+
+        impl $crate::coordinates::CoordinateSystem for $name {
+            type Dimension = $dim;
+        }
+    };
+}
+
+/// Implements `ConversionTo<To>` for the system `From` using a closure mapping
+/// `&Point<From>` to `Point<To>`, and optionally a closure calculating the Jacobian as a
+/// `Matrix<To>` at the converted point (otherwise it is calculated numerically).
+///
+/// The expansion checks at compile time that both systems have the same dimension, which
+/// is required for converting tensors.
+///
+/// For example, `conversion!(Polar => Cartesian, |p| ..., jacobian = |p| ...)`.
+#[macro_export]
+macro_rules! conversion {
+    ($from: ty => $to: ty, $convert: expr, jacobian = $jacobian: expr) => {
+        $crate::conversion!(@impl $from => $to, $convert, {
+            fn jacobian(p: &$crate::coordinates::Point<Self>) -> $crate::tensors::Matrix<$to> {
+                ($jacobian)(p)
+            }
+        });
+    };
+    ($from: ty => $to: ty, $convert: expr) => {
+        $crate::conversion!(@impl $from => $to, $convert, {});
+    };
+    (@impl $from: ty => $to: ty, $convert: expr, { $($jacobian: item)* }) => {
+        const _: fn() = || {
+            fn same_dimension<A, B: $crate::typenum::Same<A>>() {}
+            same_dimension::<
+                <$from as $crate::coordinates::CoordinateSystem>::Dimension,
+                <$to as $crate::coordinates::CoordinateSystem>::Dimension,
+            >();
+        };
+
+        impl $crate::coordinates::ConversionTo<$to> for $from {
+            fn convert_point(
+                p: &$crate::coordinates::Point<Self>,
+            ) -> $crate::coordinates::Point<$to> {
+                ($convert)(p)
+            }
+
+            $($jacobian)*
+        }
+    };
+}
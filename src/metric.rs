@@ -1,9 +1,10 @@
-use super::coordinates::{CoordinateSystem, Point};
+use super::connection::{Connection, LeviCivita};
+use super::coordinates::{CoordinateSystem, Embedding, Point, SpatialAxes, SubSystem};
 use super::tensors::{
-    ContravariantIndex, CovariantIndex, InnerProduct, InvTwoForm, Tensor, TwoForm,
+    ContravariantIndex, CovariantIndex, Covector, InnerProduct, InvTwoForm, Tensor, TwoForm, Vector,
 };
 use crate::inner;
-use crate::typenum::consts::{U0, U1, U2, U3};
+use crate::typenum::consts::{U0, U1, U2, U3, U4};
 use crate::typenum::{Exp, Pow, Unsigned};
 use generic_array::ArrayLength;
 
@@ -27,25 +28,24 @@ where
 
     /// Returns the partial derivatives of the metric at a given point.
     ///
-    /// The default implementation calculates them numerically. A direct implementation
-    /// may be desirable for performance.
+    /// The default implementation calculates them numerically, using the method returned
+    /// by `diff_method`. A direct implementation may be desirable for performance.
     fn dg(point: &Point<Self>) -> Tensor<Self, (CovariantIndex, (CovariantIndex, CovariantIndex))> {
         let d = Self::dimension();
         let mut result = Tensor::zero(point.clone());
-        let h = Self::small(point);
 
         for j in 0..d {
-            let mut x = point.clone();
-            x[j] = x[j] - h;
-            let g1 = Self::g(&x);
-
-            x[j] = x[j] + h * 2.0;
-            let g2 = Self::g(&x);
+            let h = Self::diff_step(point, j);
+            for &(offset, weight) in Self::diff_method().stencil() {
+                let mut x = point.clone();
+                x[j] += offset * h;
+                let g = Self::g(&x);
 
-            for coord in g1.iter_coords() {
-                // calculate dg_i/dx^j
-                let index = [coord[0], coord[1], j];
-                result[&index[..]] = (g2[&*coord] - g1[&*coord]) / (2.0 * h);
+                for coord in g.iter_coords() {
+                    // calculate dg_i/dx^j
+                    let index = [coord[0], coord[1], j];
+                    result[&index[..]] += weight * g[&*coord] / h;
+                }
             }
         }
 
@@ -87,6 +87,121 @@ where
             U2,
         >>::inner_product(ig, gamma)
     }
+
+    /// Returns the Riemann curvature tensor R^ρ_{σμν}, with the indices in this order.
+    ///
+    /// The default implementation calculates it from the Christoffel symbols, as the
+    /// curvature of the `LeviCivita` connection. A direct implementation may be desirable
+    /// for accuracy and performance.
+    #[allow(clippy::type_complexity)]
+    fn riemann(
+        point: &Point<Self>,
+    ) -> Tensor<
+        Self,
+        (
+            ContravariantIndex,
+            (CovariantIndex, (CovariantIndex, CovariantIndex)),
+        ),
+    >
+    where
+        <Self as CoordinateSystem>::Dimension: Pow<U4>,
+        Exp<<Self as CoordinateSystem>::Dimension, U4>: ArrayLength<f64>,
+    {
+        <LeviCivita<Self> as Connection<Self>>::riemann(point)
+    }
+
+    /// Returns the Ricci tensor R_{σν} = R^ρ_{σρν}.
+    fn ricci(point: &Point<Self>) -> TwoForm<Self>
+    where
+        <Self as CoordinateSystem>::Dimension: Pow<U4>,
+        Exp<<Self as CoordinateSystem>::Dimension, U4>: ArrayLength<f64>,
+    {
+        let riemann = Self::riemann(point);
+        let d = Self::dimension();
+        let mut result = TwoForm::<Self>::zero(point.clone());
+        for s in 0..d {
+            for n in 0..d {
+                result[&[s, n][..]] = (0..d).map(|r| riemann[&[r, s, r, n][..]]).sum();
+            }
+        }
+        result
+    }
+
+    /// Returns the Ricci scalar R = g^{σν} R_{σν}.
+    fn ricci_scalar(point: &Point<Self>) -> f64
+    where
+        <Self as CoordinateSystem>::Dimension: Pow<U4>,
+        Exp<<Self as CoordinateSystem>::Dimension, U4>: ArrayLength<f64>,
+    {
+        let ig = Self::inv_g(point);
+        let ricci = Self::ricci(point);
+        ig.coords_array()
+            .iter()
+            .zip(ricci.coords_array().iter())
+            .map(|(x, y)| x * y)
+            .sum()
+    }
+
+    /// Checks whether the metric is flat at a given point, i.e. whether all the components
+    /// of the Riemann tensor there have absolute values at most `tol`. A metric flat
+    /// everywhere means that the coordinate system is just a chart on a flat space.
+    ///
+    /// Unless `riemann` or `christoffel` is implemented directly, the Riemann tensor is
+    /// calculated with numerical derivatives, so even for a flat metric its components are
+    /// only zero up to the errors of the finite differences, which depend on `small`,
+    /// `diff_method` and the scale of the coordinates. The tolerance has to be chosen
+    /// accordingly - e.g. 1e-6 is usually too strict for central differences with the
+    /// default step.
+    fn is_flat(point: &Point<Self>, tol: f64) -> bool
+    where
+        <Self as CoordinateSystem>::Dimension: Pow<U4>,
+        Exp<<Self as CoordinateSystem>::Dimension, U4>: ArrayLength<f64>,
+    {
+        Self::riemann(point)
+            .coords_array()
+            .iter()
+            .all(|x| x.abs() <= tol)
+    }
+
+    /// Returns the Kretschmann scalar R_{abcd} R^{abcd}.
+    fn kretschmann(point: &Point<Self>) -> f64
+    where
+        <Self as CoordinateSystem>::Dimension: Pow<U4>,
+        Exp<<Self as CoordinateSystem>::Dimension, U4>: ArrayLength<f64>,
+    {
+        let d = Self::dimension();
+        let g = Self::g(point);
+        let ig = Self::inv_g(point);
+        let riemann = Self::riemann(point);
+
+        // lowers or raises the k-th index (counting from the last one) of a rank 4 tensor
+        // stored in a flat array, using the given metric
+        let transform = |x: &[f64], metric: &[f64], k: u32| -> Vec<f64> {
+            let stride = d.pow(k);
+            let mut result = vec![0.0; x.len()];
+            for (i, value) in result.iter_mut().enumerate() {
+                let idx = (i / stride) % d;
+                let base = i - idx * stride;
+                *value = (0..d)
+                    .map(|j| metric[idx * d + j] * x[base + j * stride])
+                    .sum();
+            }
+            result
+        };
+
+        let lower = transform(riemann.coords_array(), g.coords_array(), 3);
+        let upper = transform(
+            &transform(
+                &transform(riemann.coords_array(), ig.coords_array(), 2),
+                ig.coords_array(),
+                1,
+            ),
+            ig.coords_array(),
+            0,
+        );
+
+        lower.iter().zip(upper.iter()).map(|(x, y)| x * y).sum()
+    }
 }
 
 impl<T> Tensor<T, ContravariantIndex>
@@ -132,3 +247,190 @@ where
         }
     }
 }
+
+impl<T> Tensor<T, (CovariantIndex, CovariantIndex)>
+where
+    T: MetricSystem,
+    T::Dimension: Pow<U1> + Pow<U2> + Pow<U3> + Unsigned,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U3>: ArrayLength<f64>,
+{
+    /// Returns the stress-energy tensor of a perfect fluid,
+    /// T_{μν} = (ρ + p) u_μ u_ν + p g_{μν}, at the point at which `velocity` is defined.
+    ///
+    /// The formula assumes the (-,+,+,+) signature. `velocity` is the four-velocity of the
+    /// fluid as a covector; it is normalized so that u·u = -1, so only its direction
+    /// matters. Panics if it isn't timelike.
+    pub fn perfect_fluid(density: f64, pressure: f64, velocity: &Covector<T>) -> Self {
+        let square = velocity.square();
+        assert!(square < -1e-12, "the four-velocity must be timelike");
+
+        let mut u = velocity.clone();
+        let len = (-square).sqrt();
+        for i in 0..T::dimension() {
+            u[i] /= len;
+        }
+
+        let mut result = T::g(velocity.get_point()) * pressure;
+        for i in 0..T::dimension() {
+            for j in 0..T::dimension() {
+                result[&[i, j][..]] += (density + pressure) * u[i] * u[j];
+            }
+        }
+        result
+    }
+}
+
+/// Returns the metric induced on `T` by its embedding in `E` at a given point - the
+/// pullback g_ij = G_ab X^a_i X^b_j of the metric G of `E`, where X_i are the tangent
+/// vectors returned by `tangent_vectors`.
+///
+/// For an embedding in `Cartesian<N>` this is JᵀJ, where J is the matrix of the derivatives
+/// of the embedding, so a `MetricSystem` can be implemented by just calling this function
+/// if the embedding is known.
+pub fn induced_metric<T, E>(point: &Point<T>) -> TwoForm<T>
+where
+    T: Embedding<E>,
+    E: MetricSystem,
+    T::Dimension: Pow<U2>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    E::Dimension: Pow<U1> + Pow<U2> + Pow<U3>,
+    Exp<E::Dimension, U1>: ArrayLength<f64>,
+    Exp<E::Dimension, U2>: ArrayLength<f64>,
+    Exp<E::Dimension, U3>: ArrayLength<f64>,
+{
+    let tangent = T::tangent_vectors(point);
+    let ambient = E::g(&T::embed_point(point));
+    let mut result = TwoForm::<T>::zero(point.clone());
+
+    for i in 0..T::dimension() {
+        for j in 0..T::dimension() {
+            result[&[i, j][..]] = tangent[i].dot(&tangent[j], &ambient);
+        }
+    }
+
+    result
+}
+
+/// Returns the Gaussian curvature of a 2-dimensional manifold at a given point.
+///
+/// The curvature is calculated directly from the metric, using Brioschi's formula, which
+/// only needs the first and second derivatives of the metric. They are calculated
+/// numerically using the method returned by `diff_method`. This avoids differentiating the
+/// Christoffel symbols, so it is more accurate than half the Ricci scalar.
+pub fn gaussian_curvature<T>(point: &Point<T>) -> f64
+where
+    T: MetricSystem<Dimension = U2>,
+{
+    let h = |a: usize| T::diff_step(point, a);
+    let stencil = T::diff_method().stencil();
+    let metric = |p: &Point<T>| {
+        let g = T::g(p);
+        [g[&[0, 0][..]], g[&[0, 1][..]], g[&[1, 1][..]]]
+    };
+
+    // first derivatives of (E, F, G) with respect to the coordinate a
+    let d1 = |a: usize| {
+        let mut result = [0.0; 3];
+        for &(offset, weight) in stencil {
+            let mut x = *point;
+            x[a] += offset * h(a);
+            for (r, m) in result.iter_mut().zip(metric(&x).iter()) {
+                *r += weight * m / h(a);
+            }
+        }
+        result
+    };
+
+    // second derivatives of (E, F, G) with respect to the coordinates a and b
+    let d2 = |a: usize, b: usize| {
+        let mut result = [0.0; 3];
+        for &(offset1, weight1) in stencil {
+            for &(offset2, weight2) in stencil {
+                let mut x = *point;
+                x[a] += offset1 * h(a);
+                x[b] += offset2 * h(b);
+                for (r, m) in result.iter_mut().zip(metric(&x).iter()) {
+                    *r += weight1 * weight2 * m / (h(a) * h(b));
+                }
+            }
+        }
+        result
+    };
+
+    let [e, f, g] = metric(point);
+    let [e_u, f_u, g_u] = d1(0);
+    let [e_v, f_v, g_v] = d1(1);
+    let e_vv = d2(1, 1)[0];
+    let f_uv = d2(0, 1)[1];
+    let g_uu = d2(0, 0)[2];
+
+    let det3 = |m: [[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+
+    let first = det3([
+        [-0.5 * e_vv + f_uv - 0.5 * g_uu, 0.5 * e_u, f_u - 0.5 * e_v],
+        [f_v - 0.5 * g_u, e, f],
+        [0.5 * g_v, f, g],
+    ]);
+    let second = det3([
+        [0.0, 0.5 * e_v, 0.5 * g_u],
+        [0.5 * e_v, e, f],
+        [0.5 * g_u, f, g],
+    ]);
+
+    (first - second) / (e * g - f * f).powi(2)
+}
+
+/// The variables of the ADM (3+1) decomposition of a spacetime metric, see `adm_split`.
+pub struct AdmVariables<T: CoordinateSystem> {
+    /// The lapse function α
+    pub lapse: f64,
+    /// The shift vector β^i
+    pub shift: Vector<SubSystem<T, SpatialAxes>>,
+    /// The spatial metric γ_ij
+    pub spatial_metric: TwoForm<SubSystem<T, SpatialAxes>>,
+}
+
+/// Decomposes the metric at a given point into the ADM variables: the lapse α, the shift
+/// β^i and the spatial metric γ_ij, so that
+///
+/// ds² = -α² dt² + γ_ij (dx^i + β^i dt)(dx^j + β^j dt).
+///
+/// The coordinate 0 is assumed to be the time, which labels the slices of the foliation,
+/// and the coordinates 1 to 3 are the spatial coordinates on the slices. The metric must
+/// have the (-,+,+,+) signature. Then γ_ij = g_ij, β_i = g_0i and α² = β_i β^i - g_00,
+/// with the index of β raised by the inverse of γ.
+///
+/// Returns `None` if the slices of constant time are not spacelike, i.e. if the spatial
+/// metric is not invertible or α² is not positive.
+pub fn adm_split<T>(point: &Point<T>) -> Option<AdmVariables<T>>
+where
+    T: MetricSystem<Dimension = U4>,
+{
+    let g = T::g(point);
+    let spatial_metric = g.project::<SpatialAxes>();
+    let inv_spatial_metric = spatial_metric.inverse()?;
+
+    let mut shift = Vector::<SubSystem<T, SpatialAxes>>::zero(*spatial_metric.get_point());
+    for i in 0..3 {
+        for j in 0..3 {
+            shift[i] += inv_spatial_metric[&[i, j][..]] * g[&[0, j + 1][..]];
+        }
+    }
+
+    let lapse_squared = (0..3).map(|i| shift[i] * g[&[0, i + 1][..]]).sum::<f64>() - g[0];
+    if lapse_squared <= 0.0 {
+        return None;
+    }
+
+    Some(AdmVariables {
+        lapse: lapse_squared.sqrt(),
+        shift,
+        spatial_metric,
+    })
+}
@@ -0,0 +1,149 @@
+//! Module containing helpers for plotting fields on two-dimensional coordinate systems with
+//! `plotters`.
+//!
+//! The helpers draw into a chart created by the caller, so that any `plotters` backend can be
+//! used, and the caller keeps control over the size, margins and axes of the plot. The chart
+//! should use the coordinates of the system as its ranges.
+//!
+//! The fields are sampled at the centers of the cells of a regular grid covering the
+//! coordinate box `bounds`, with `resolution` cells along each axis.
+
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::field::{sample_field, TensorField};
+use crate::tensors::ContravariantIndex;
+use crate::typenum::consts::U2;
+use plotters::coord::types::RangedCoordf64;
+use plotters::prelude::*;
+
+/// The chart type the helpers draw into - a Cartesian chart with `f64` coordinates.
+pub type Chart<'a, DB> = ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+
+/// The result of drawing with the backend `DB`.
+pub type DrawResult<DB> = Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>;
+
+// Returns the coordinate box shrunk by half a cell on each side, so that sampling it at the
+// given resolution (with the ends included) gives the values at the centers of the cells
+fn cell_centers(bounds: &[(f64, f64); 2], resolution: &[usize; 2]) -> [(f64, f64); 2] {
+    let shrink = |(min, max): (f64, f64), n: usize| {
+        let half = (max - min) / n as f64 / 2.0;
+        (min + half, max - half)
+    };
+    [
+        shrink(bounds[0], resolution[0]),
+        shrink(bounds[1], resolution[1]),
+    ]
+}
+
+// Returns the coordinates of the center of the k-th cell, in the order used by sample_field
+fn cell_center(centers: &[(f64, f64); 2], resolution: &[usize; 2], k: usize) -> (f64, f64) {
+    let coordinate = |(min, max): (f64, f64), n: usize, i: usize| {
+        if n > 1 {
+            min + (max - min) * i as f64 / (n - 1) as f64
+        } else {
+            min
+        }
+    };
+    (
+        coordinate(centers[0], resolution[0], k / resolution[1]),
+        coordinate(centers[1], resolution[1], k % resolution[1]),
+    )
+}
+
+/// Draws a scalar field as a heatmap: every cell of the grid is filled with a color from the
+/// Viridis color map, scaled between the smallest and the largest value of the field on the
+/// grid.
+pub fn draw_heatmap<T, F, DB>(
+    chart: &mut Chart<'_, DB>,
+    field: &F,
+    bounds: [(f64, f64); 2],
+    resolution: [usize; 2],
+) -> DrawResult<DB>
+where
+    T: CoordinateSystem<Dimension = U2>,
+    F: TensorField<T, ()>,
+    DB: DrawingBackend,
+{
+    let centers = cell_centers(&bounds, &resolution);
+    let values = sample_field(field, &centers, &resolution, 0);
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    let (dx, dy) = (
+        (bounds[0].1 - bounds[0].0) / resolution[0] as f64,
+        (bounds[1].1 - bounds[1].0) / resolution[1] as f64,
+    );
+    chart.draw_series(values.iter().enumerate().map(|(k, &value)| {
+        let (x, y) = cell_center(&centers, &resolution, k);
+        // a constant field is drawn in the middle of the color map
+        let color = if max > min {
+            ViridisRGB.get_color_normalized(value, min, max)
+        } else {
+            ViridisRGB.get_color(0.5)
+        };
+        Rectangle::new(
+            [(x - dx / 2.0, y - dy / 2.0), (x + dx / 2.0, y + dy / 2.0)],
+            color.filled(),
+        )
+    }))?;
+    Ok(())
+}
+
+/// Draws a vector field as a quiver plot: an arrow at the center of every cell of the grid,
+/// pointing along the vector at that point.
+///
+/// The arrows are scaled together, so that the longest one is 90% of the size of a cell.
+pub fn draw_quiver<T, F, DB>(
+    chart: &mut Chart<'_, DB>,
+    field: &F,
+    bounds: [(f64, f64); 2],
+    resolution: [usize; 2],
+    style: ShapeStyle,
+) -> DrawResult<DB>
+where
+    T: CoordinateSystem<Dimension = U2>,
+    F: TensorField<T, ContravariantIndex>,
+    DB: DrawingBackend,
+{
+    let centers = cell_centers(&bounds, &resolution);
+    let vectors: Vec<(f64, f64)> = (0..resolution[0] * resolution[1])
+        .map(|k| {
+            let (x, y) = cell_center(&centers, &resolution, k);
+            let v = field.value_at(&Point::from_slice(&[x, y]));
+            (v[0], v[1])
+        })
+        .collect();
+
+    let cell = ((bounds[0].1 - bounds[0].0) / resolution[0] as f64)
+        .min((bounds[1].1 - bounds[1].0) / resolution[1] as f64);
+    let longest = vectors
+        .iter()
+        .map(|(vx, vy)| vx.hypot(*vy))
+        .fold(0.0, f64::max);
+    let scale = if longest > 0.0 {
+        0.9 * cell / longest
+    } else {
+        0.0
+    };
+
+    chart.draw_series(vectors.iter().enumerate().map(|(k, &(vx, vy))| {
+        let (x, y) = cell_center(&centers, &resolution, k);
+        let (dx, dy) = (vx * scale, vy * scale);
+        let (tip_x, tip_y) = (x + dx / 2.0, y + dy / 2.0);
+        // the head consists of two strokes, at 30 degrees from the shaft, a quarter of its
+        // length long
+        let (cos, sin) = (0.75_f64.sqrt() / 4.0, 0.5 / 4.0);
+        let left = (tip_x - cos * dx + sin * dy, tip_y - cos * dy - sin * dx);
+        let right = (tip_x - cos * dx - sin * dy, tip_y - cos * dy + sin * dx);
+        PathElement::new(
+            vec![
+                (x - dx / 2.0, y - dy / 2.0),
+                (tip_x, tip_y),
+                left,
+                (tip_x, tip_y),
+                right,
+            ],
+            style,
+        )
+    }))?;
+    Ok(())
+}
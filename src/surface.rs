@@ -0,0 +1,103 @@
+//! Module containing functions describing the geometry of surfaces embedded in the
+//! three-dimensional Euclidean space.
+//!
+//! The surfaces are coordinate systems of dimension 2 implementing `Embedding<Cartesian<U3>>`.
+//! The unit normal used throughout is n = X_u × X_v / |X_u × X_v|, where X_u and X_v are the
+//! tangent vectors returned by `tangent_vectors`, so the signs of the second fundamental form
+//! and of the principal curvatures depend on the orientation of the coordinates.
+
+use crate::coordinates::{CoordinateSystem, Embedding, Point};
+use crate::systems::Cartesian;
+use crate::tensors::linalg;
+use crate::tensors::{Matrix, TwoForm};
+use crate::typenum::consts::{U2, U3};
+
+// Returns the cross product of two vectors
+fn cross(a: &[f64], b: &[f64]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Returns the first and the second fundamental form of the surface at a given point.
+///
+/// The first fundamental form is the metric induced by the embedding, I_ij = X_i · X_j. The
+/// second one is II_ij = X_ij · n. The second derivatives X_ij are calculated by numerically
+/// differentiating the tangent vectors, using the method returned by `diff_method`.
+pub fn fundamental_forms<T>(point: &Point<T>) -> (TwoForm<T>, TwoForm<T>)
+where
+    T: Embedding<Cartesian<U3>> + CoordinateSystem<Dimension = U2>,
+{
+    let tangent = T::tangent_vectors(point);
+    let normal = cross(tangent[0].coords_array(), tangent[1].coords_array());
+    let len = dot(&normal, &normal).sqrt();
+    let normal: Vec<f64> = normal.iter().map(|x| x / len).collect();
+
+    let mut first = TwoForm::<T>::zero(*point);
+    let mut second = TwoForm::<T>::zero(*point);
+
+    for j in 0..2 {
+        let h = T::diff_step(point, j);
+        for i in 0..2 {
+            let idx: &[usize] = &[i, j];
+            first[idx] = dot(tangent[i].coords_array(), tangent[j].coords_array());
+        }
+
+        for &(offset, weight) in T::diff_method().stencil() {
+            let mut x = *point;
+            x[j] += offset * h;
+            let tangent_x = T::tangent_vectors(&x);
+
+            for (i, t) in tangent_x.iter().enumerate() {
+                second[&[i, j][..]] += weight * dot(t.coords_array(), &normal) / h;
+            }
+        }
+    }
+
+    // X_ij is symmetric, so symmetrize to reduce the numerical error
+    let off_diagonal = 0.5 * (second[&[0, 1][..]] + second[&[1, 0][..]]);
+    second[&[0, 1][..]] = off_diagonal;
+    second[&[1, 0][..]] = off_diagonal;
+
+    (first, second)
+}
+
+/// Returns the shape operator S^i_j = (I^-1)^ik II_kj of the surface at a given point.
+pub fn shape_operator<T>(point: &Point<T>) -> Matrix<T>
+where
+    T: Embedding<Cartesian<U3>> + CoordinateSystem<Dimension = U2>,
+{
+    let (first, second) = fundamental_forms(point);
+    let inv_first = linalg::inverse(first.coords_array(), 2).unwrap();
+    let result = linalg::matmul(&inv_first, second.coords_array(), 2);
+    Matrix::<T>::from_slice(*point, &result)
+}
+
+/// Returns the principal curvatures of the surface at a given point, in ascending order.
+///
+/// The principal curvatures are the eigenvalues of the shape operator. It isn't symmetric
+/// in general, so they are calculated as the eigenvalues of the symmetric matrix
+/// I^-1/2 II I^-1/2, which has the same eigenvalues.
+pub fn principal_curvatures<T>(point: &Point<T>) -> [f64; 2]
+where
+    T: Embedding<Cartesian<U3>> + CoordinateSystem<Dimension = U2>,
+{
+    let (first, second) = fundamental_forms(point);
+    let (values, vectors) = linalg::symmetric_eigen(first.coords_array(), 2);
+    let inv_sqrt = linalg::symmetric_function(&values, &vectors, 2, |x| 1.0 / x.sqrt());
+    let sym = linalg::matmul(
+        &linalg::matmul(&inv_sqrt, second.coords_array(), 2),
+        &inv_sqrt,
+        2,
+    );
+
+    let (mut curvatures, _) = linalg::symmetric_eigen(&sym, 2);
+    curvatures.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    [curvatures[0], curvatures[1]]
+}
@@ -0,0 +1,38 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::metric::MetricSystem;
+use crate::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm};
+use crate::typenum::consts::{U2, U3};
+use crate::typenum::{Exp, Pow, Unsigned};
+use generic_array::ArrayLength;
+use std::marker::PhantomData;
+
+/// The Cartesian coordinates of the Euclidean space of dimension `N`.
+///
+/// The metric is the identity.
+pub struct Cartesian<N>(PhantomData<N>);
+
+impl<N> CoordinateSystem for Cartesian<N>
+where
+    N: Unsigned + ArrayLength<f64> + ArrayLength<usize>,
+{
+    type Dimension = N;
+}
+
+impl<N> MetricSystem for Cartesian<N>
+where
+    N: Unsigned + ArrayLength<f64> + ArrayLength<usize> + Pow<U2> + Pow<U3>,
+    Exp<N, U2>: ArrayLength<f64>,
+    Exp<N, U3>: ArrayLength<f64>,
+{
+    fn g(point: &Point<Self>) -> TwoForm<Self> {
+        Tensor::<Self, (CovariantIndex, CovariantIndex)>::unit(point.clone())
+    }
+
+    fn inv_g(point: &Point<Self>) -> InvTwoForm<Self> {
+        Tensor::<Self, (ContravariantIndex, ContravariantIndex)>::unit(point.clone())
+    }
+
+    fn dg(point: &Point<Self>) -> Tensor<Self, (CovariantIndex, (CovariantIndex, CovariantIndex))> {
+        Tensor::zero(point.clone())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::metric::MetricSystem;
+use crate::tensors::{InvTwoForm, TwoForm};
+use crate::typenum::consts::U4;
+use std::marker::PhantomData;
+
+/// The sign convention of a metric of a spacetime.
+pub trait Signature {
+    /// The sign of the time component of the metric. The spatial components have the
+    /// opposite sign.
+    const TIME_SIGN: f64;
+}
+
+/// The (-,+,+,+) signature, in which spacelike vectors have positive squares.
+pub struct MostlyPlus;
+
+impl Signature for MostlyPlus {
+    const TIME_SIGN: f64 = -1.0;
+}
+
+/// The (+,-,-,-) signature, in which timelike vectors have positive squares.
+pub struct MostlyMinus;
+
+impl Signature for MostlyMinus {
+    const TIME_SIGN: f64 = 1.0;
+}
+
+/// The flat spacetime of special relativity, with coordinates (t, x, y, z) and units in
+/// which c = 1.
+///
+/// The metric is diag(s, -s, -s, -s), where s is the time sign of the signature `S`. By
+/// default, the (-,+,+,+) signature is used, i.e. the metric is diag(-1, 1, 1, 1).
+pub struct Minkowski<S: Signature = MostlyPlus>(PhantomData<S>);
+
+impl<S: Signature> CoordinateSystem for Minkowski<S> {
+    type Dimension = U4;
+
+    fn coordinate_labels() -> &'static [&'static str] {
+        &["t", "x", "y", "z"]
+    }
+}
+
+impl<S: Signature> MetricSystem for Minkowski<S> {
+    fn g(point: &Point<Self>) -> TwoForm<Self> {
+        let mut result = TwoForm::<Self>::zero(*point);
+        result[&[0, 0][..]] = S::TIME_SIGN;
+        for i in 1..4 {
+            result[&[i, i][..]] = -S::TIME_SIGN;
+        }
+        result
+    }
+
+    fn inv_g(point: &Point<Self>) -> InvTwoForm<Self> {
+        // the metric is its own inverse
+        InvTwoForm::<Self>::new(*point, *Self::g(point).coords_array())
+    }
+}
@@ -0,0 +1,11 @@
+//! Module containing commonly used coordinate systems.
+
+mod cartesian;
+mod minkowski;
+mod schwarzschild;
+mod sphere;
+
+pub use self::cartesian::Cartesian;
+pub use self::minkowski::{Minkowski, MostlyMinus, MostlyPlus, Signature};
+pub use self::schwarzschild::{Mass, Schwarzschild, UnitMass};
+pub use self::sphere::S2;
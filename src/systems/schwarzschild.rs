@@ -0,0 +1,82 @@
+use crate::coordinates::{CoordinateSystem, DiffMethod, Point};
+use crate::metric::MetricSystem;
+use crate::tensors::{InvTwoForm, TwoForm};
+use crate::typenum::consts::U4;
+use std::marker::PhantomData;
+
+/// The mass of a central body, in geometrized units (G = c = 1).
+pub trait Mass {
+    /// The value of the mass
+    const MASS: f64;
+}
+
+/// A mass equal to 1.
+pub struct UnitMass;
+
+impl Mass for UnitMass {
+    const MASS: f64 = 1.0;
+}
+
+/// The spacetime around a spherically symmetric, uncharged, non-rotating body of mass `M`,
+/// in the Schwarzschild coordinates (t, r, θ, φ).
+///
+/// The metric is diag(-(1 - 2M/r), 1 / (1 - 2M/r), r^2, r^2 sin^2 θ), using the (-,+,+,+)
+/// signature. It is singular at the origin and at the horizon r = 2M, so the metric
+/// functions panic at these points.
+pub struct Schwarzschild<M: Mass = UnitMass>(PhantomData<M>);
+
+impl<M: Mass> Schwarzschild<M> {
+    // Returns 1 - 2M/r, checking that the point is neither at the origin nor at the horizon
+    fn lapse_squared(point: &Point<Self>) -> f64 {
+        let r = point[1];
+        assert!(r != 0.0, "the Schwarzschild metric is singular at r = 0");
+        let result = 1.0 - 2.0 * M::MASS / r;
+        assert!(
+            result.abs() > 1e-12,
+            "the Schwarzschild coordinates are singular at the horizon r = 2M"
+        );
+        result
+    }
+}
+
+impl<M: Mass> CoordinateSystem for Schwarzschild<M> {
+    type Dimension = U4;
+
+    fn coordinate_labels() -> &'static [&'static str] {
+        &["t", "r", "theta", "phi"]
+    }
+
+    // the curvature is calculated by differentiating the metric twice, so a more accurate
+    // method than the default one pays off
+    fn diff_method() -> DiffMethod {
+        DiffMethod::FivePoint
+    }
+}
+
+impl<M: Mass> MetricSystem for Schwarzschild<M> {
+    fn g(point: &Point<Self>) -> TwoForm<Self> {
+        let f = Self::lapse_squared(point);
+        let r = point[1];
+        let sin_theta = point[2].sin();
+
+        let mut result = TwoForm::<Self>::zero(*point);
+        result[&[0, 0][..]] = -f;
+        result[&[1, 1][..]] = 1.0 / f;
+        result[&[2, 2][..]] = r * r;
+        result[&[3, 3][..]] = r * r * sin_theta * sin_theta;
+        result
+    }
+
+    fn inv_g(point: &Point<Self>) -> InvTwoForm<Self> {
+        let f = Self::lapse_squared(point);
+        let r = point[1];
+        let sin_theta = point[2].sin();
+
+        let mut result = InvTwoForm::<Self>::zero(*point);
+        result[&[0, 0][..]] = -1.0 / f;
+        result[&[1, 1][..]] = f;
+        result[&[2, 2][..]] = 1.0 / (r * r);
+        result[&[3, 3][..]] = 1.0 / (r * r * sin_theta * sin_theta);
+        result
+    }
+}
@@ -0,0 +1,92 @@
+use super::Cartesian;
+use crate::coordinates::{CoordinateSystem, DiffMethod, Embedding, Point};
+use crate::metric::MetricSystem;
+use crate::tensors::{ContravariantIndex, CovariantIndex, InvTwoForm, Tensor, TwoForm, Vector};
+use crate::typenum::consts::{U2, U3};
+use generic_array::arr;
+
+/// The unit sphere, with the coordinates (θ, φ) - the polar angle measured from the
+/// z axis and the azimuthal angle.
+///
+/// At the poles, where sin θ = 0, the coordinates are degenerate. The quantities involving
+/// 1 / sin θ are evaluated with sin θ clamped to a small nonzero value there, so that they
+/// stay finite instead of becoming infinite or NaN.
+pub struct S2;
+
+impl S2 {
+    // Returns sin θ, clamped away from zero
+    fn safe_sin(point: &Point<Self>) -> f64 {
+        const MIN_SIN: f64 = 1e-8;
+        let sin_theta = point[0].sin();
+        if sin_theta.abs() < MIN_SIN {
+            MIN_SIN.copysign(sin_theta)
+        } else {
+            sin_theta
+        }
+    }
+}
+
+impl CoordinateSystem for S2 {
+    type Dimension = U2;
+
+    fn coordinate_labels() -> &'static [&'static str] {
+        &["theta", "phi"]
+    }
+
+    // the curvature is calculated by differentiating the Christoffel symbols numerically
+    fn diff_method() -> DiffMethod {
+        DiffMethod::FivePoint
+    }
+}
+
+impl MetricSystem for S2 {
+    fn g(point: &Point<Self>) -> TwoForm<Self> {
+        let sin_theta = point[0].sin();
+        TwoForm::<Self>::new(*point, arr![f64; 1.0, 0.0, 0.0, sin_theta * sin_theta])
+    }
+
+    fn inv_g(point: &Point<Self>) -> InvTwoForm<Self> {
+        let sin_theta = Self::safe_sin(point);
+        InvTwoForm::<Self>::new(
+            *point,
+            arr![f64; 1.0, 0.0, 0.0, 1.0 / (sin_theta * sin_theta)],
+        )
+    }
+
+    fn christoffel(
+        point: &Point<Self>,
+    ) -> Tensor<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        let sin_theta = Self::safe_sin(point);
+        let cos_theta = point[0].cos();
+        let mut result =
+            Tensor::<Self, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::zero(*point);
+
+        // Γ^θ_φφ = -sin θ cos θ, Γ^φ_θφ = Γ^φ_φθ = cot θ
+        result[&[0, 1, 1][..]] = -sin_theta * cos_theta;
+        result[&[1, 0, 1][..]] = cos_theta / sin_theta;
+        result[&[1, 1, 0][..]] = cos_theta / sin_theta;
+        result
+    }
+}
+
+impl Embedding<Cartesian<U3>> for S2 {
+    fn embed_point(p: &Point<Self>) -> Point<Cartesian<U3>> {
+        let (theta, phi) = (p[0], p[1]);
+        Point::new(arr![f64; theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos()])
+    }
+
+    fn tangent_vectors(p: &Point<Self>) -> Vec<Vector<Cartesian<U3>>> {
+        let (theta, phi) = (p[0], p[1]);
+        let q = Self::embed_point(p);
+        vec![
+            Vector::<Cartesian<U3>>::new(
+                q,
+                arr![f64; theta.cos() * phi.cos(), theta.cos() * phi.sin(), -theta.sin()],
+            ),
+            Vector::<Cartesian<U3>>::new(
+                q,
+                arr![f64; -theta.sin() * phi.sin(), theta.sin() * phi.cos(), 0.0],
+            ),
+        ]
+    }
+}
@@ -0,0 +1,232 @@
+//! This module defines complex-valued tensors.
+
+use super::linalg;
+use super::{ContravariantIndex, CovariantIndex, Tensor, TensorIndex, TwoForm, Variance};
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::typenum::{
+    consts::{B1, U2},
+    uint::Unsigned,
+    Add1, Exp, Pow,
+};
+use generic_array::ArrayLength;
+use num_complex::Complex;
+use std::ops::Add;
+
+/// Struct representing a tensor with complex coordinates.
+///
+/// The real and imaginary parts are stored as two ordinary tensors
+/// anchored at the same point, so all the type-level guarantees of
+/// `Tensor` carry over.
+pub struct ComplexTensor<T: CoordinateSystem, U: Variance>
+where
+    T::Dimension: Pow<U::Rank>,
+    Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
+{
+    re: Tensor<T, U>,
+    im: Tensor<T, U>,
+}
+
+impl<T, U> Clone for ComplexTensor<T, U>
+where
+    T: CoordinateSystem,
+    U: Variance,
+    T::Dimension: Pow<U::Rank>,
+    Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            re: self.re.clone(),
+            im: self.im.clone(),
+        }
+    }
+}
+
+impl<T, V> ComplexTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Creates a complex tensor from its real and imaginary parts.
+    ///
+    /// Both parts must be defined at the same point.
+    pub fn new(re: Tensor<T, V>, im: Tensor<T, V>) -> Self {
+        assert!(re.get_point().approx_eq(im.get_point()));
+        Self { re, im }
+    }
+
+    /// Creates a new, zero complex tensor at a given point
+    pub fn zero(point: Point<T>) -> Self {
+        Self {
+            re: Tensor::zero(point.clone()),
+            im: Tensor::zero(point),
+        }
+    }
+
+    /// Creates a complex tensor at a given point with the coordinates defined by the slice.
+    ///
+    /// The ordering of the coordinates is the same as in `Tensor::from_slice`.
+    pub fn from_slice(point: Point<T>, slice: &[Complex<f64>]) -> Self {
+        assert_eq!(Tensor::<T, V>::get_num_coords(), slice.len());
+        let mut result = Self::zero(point);
+        for (i, z) in slice.iter().enumerate() {
+            result.re[i] = z.re;
+            result.im[i] = z.im;
+        }
+        result
+    }
+
+    /// Returns the point at which the tensor is defined.
+    pub fn get_point(&self) -> &Point<T> {
+        self.re.get_point()
+    }
+
+    /// Returns the real part of the tensor
+    pub fn re(&self) -> &Tensor<T, V> {
+        &self.re
+    }
+
+    /// Returns the imaginary part of the tensor
+    pub fn im(&self) -> &Tensor<T, V> {
+        &self.im
+    }
+
+    /// Returns the coordinate at the given indices
+    pub fn get(&self, idx: &[usize]) -> Complex<f64> {
+        Complex::new(self.re[idx], self.im[idx])
+    }
+
+    /// Sets the coordinate at the given indices
+    pub fn set(&mut self, idx: &[usize], value: Complex<f64>) {
+        self.re[idx] = value.re;
+        self.im[idx] = value.im;
+    }
+
+    /// Returns the complex conjugate of the tensor
+    pub fn conjugate(&self) -> Self {
+        Self {
+            re: self.re.clone(),
+            im: self.im.clone() * -1.0,
+        }
+    }
+}
+
+impl<T, Ul, Ur> ComplexTensor<T, (Ul, Ur)>
+where
+    T: CoordinateSystem,
+    Ul: TensorIndex,
+    Ur: TensorIndex,
+    Add1<Ur::Rank>: Unsigned + Add<B1>,
+    T::Dimension: Pow<Add1<Ur::Rank>>,
+    Exp<T::Dimension, Add1<Ur::Rank>>: ArrayLength<f64>,
+{
+    /// Checks whether the matrix is Hermitian, i.e. equal to its conjugate transpose,
+    /// with every coordinate compared up to the tolerance `tol`.
+    pub fn is_hermitian(&self, tol: f64) -> bool {
+        let n = T::dimension();
+        (0..n).all(|i| {
+            (0..n).all(|j| {
+                let ij: &[usize] = &[i, j];
+                let ji: &[usize] = &[j, i];
+                (self.get(ij) - self.get(ji).conj()).norm() <= tol
+            })
+        })
+    }
+}
+
+// Returns the Kronecker product of two 2 x 2 matrices stored row by row
+fn kron2(a: &[Complex<f64>; 4], b: &[Complex<f64>; 4]) -> Vec<Complex<f64>> {
+    let mut result = vec![Complex::new(0.0, 0.0); 16];
+    for i in 0..2 {
+        for j in 0..2 {
+            for k in 0..2 {
+                for l in 0..2 {
+                    result[(2 * i + k) * 4 + 2 * j + l] = a[2 * i + j] * b[2 * k + l];
+                }
+            }
+        }
+    }
+    result
+}
+
+// Returns the generators Γ_a of the Clifford algebra of the n-dimensional Euclidean space,
+// satisfying {Γ_a, Γ_b} = 2δ_ab, as n x n matrices. Their size is 2^(n/2) (rounded down),
+// so this is only possible for n = 1, 2 and 4.
+fn euclidean_generators(n: usize) -> Option<Vec<Vec<Complex<f64>>>> {
+    let zero = Complex::new(0.0, 0.0);
+    let one = Complex::new(1.0, 0.0);
+    let i = Complex::new(0.0, 1.0);
+    let id = [one, zero, zero, one];
+    let sx = [zero, one, one, zero];
+    let sy = [zero, -i, i, zero];
+    let sz = [one, zero, zero, -one];
+    match n {
+        1 => Some(vec![vec![one]]),
+        2 => Some(vec![sx.to_vec(), sy.to_vec()]),
+        4 => Some(vec![
+            kron2(&sx, &sx),
+            kron2(&sx, &sy),
+            kron2(&sx, &sz),
+            kron2(&sy, &id),
+        ]),
+        _ => None,
+    }
+}
+
+impl<T> ComplexTensor<T, (ContravariantIndex, CovariantIndex)>
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U2>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+{
+    /// Generates the gamma matrices γ^μ of the Clifford algebra of the given (symmetric)
+    /// metric, satisfying {γ^μ, γ^ν} = 2g^{μν} I, where g^{μν} is the inverse metric.
+    ///
+    /// The spinor index of a gamma matrix takes 2^(n/2) values (rounded down) in n
+    /// dimensions, which matches the range of the tensor indices only for n = 1, 2 and 4;
+    /// `None` is returned for other dimensions, as well as for a degenerate metric.
+    ///
+    /// The matrices are built by diagonalizing the metric to find an orthonormal frame
+    /// e_a, and combining the standard generators Γ_a (products of Pauli matrices) as
+    /// γ^μ = e^μ_a Γ_a, with the generators of the timelike directions multiplied by i.
+    /// Any other representation is related to this one by a similarity transformation.
+    pub fn gamma_matrices(metric: &TwoForm<T>) -> Option<Vec<Self>> {
+        let n = T::dimension();
+        let generators = euclidean_generators(n)?;
+        let (values, vectors) = linalg::symmetric_eigen(metric.coords_array(), n);
+        let max = values.iter().map(|x| x.abs()).fold(0.0, f64::max);
+        if values.iter().any(|x| x.abs() <= 1e-14 * max) {
+            return None;
+        }
+
+        // g^{μν} = Σ_a E_μa E_νa / λ_a, so the generators are scaled by 1/sqrt(λ_a)
+        let frame: Vec<Vec<Complex<f64>>> = generators
+            .into_iter()
+            .zip(values)
+            .map(|(generator, value)| {
+                let scale = if value > 0.0 {
+                    Complex::new(1.0 / value.sqrt(), 0.0)
+                } else {
+                    Complex::new(0.0, 1.0 / (-value).sqrt())
+                };
+                generator.into_iter().map(|z| z * scale).collect()
+            })
+            .collect();
+
+        let gammas = (0..n)
+            .map(|mu| {
+                let mut gamma = Self::zero(metric.get_point().clone());
+                for (a, generator) in frame.iter().enumerate() {
+                    let e = vectors[mu * n + a];
+                    for (k, z) in generator.iter().enumerate() {
+                        gamma.re[k] += e * z.re;
+                        gamma.im[k] += e * z.im;
+                    }
+                }
+                gamma
+            })
+            .collect();
+        Some(gammas)
+    }
+}
@@ -0,0 +1,184 @@
+//! This module defines the `Density` and `TensorDensity` types, representing scalar and
+//! tensor densities.
+
+use super::{IndexType, Tensor, Variance};
+use crate::coordinates::{ConversionTo, CoordinateSystem, Point};
+use crate::typenum::{consts::U2, Exp, Pow, Same};
+use core::fmt;
+use generic_array::ArrayLength;
+
+// Returns |det(∂x/∂x')|^weight, given the determinant of the Jacobian ∂x'/∂x
+fn weight_factor(det: f64, weight: i32) -> f64 {
+    let base = if weight > 0 {
+        1.0 / det.abs()
+    } else {
+        det.abs()
+    };
+    (0..weight.unsigned_abs()).fold(1.0, |factor, _| factor * base)
+}
+
+/// Struct representing a scalar density of weight 1 at a point - a quantity like a
+/// probability density or a Lagrangian density, whose integral over a region doesn't
+/// depend on the coordinate system.
+///
+/// Unlike a scalar, its value changes under a change of coordinates x → x': it is
+/// multiplied by |det(∂x/∂x')|, the factor by which the conversion scales volumes.
+pub struct Density<T: CoordinateSystem> {
+    p: Point<T>,
+    x: f64,
+}
+
+impl<T: CoordinateSystem> Density<T> {
+    /// Creates a density with the given value at a point.
+    pub fn new(point: Point<T>, value: f64) -> Self {
+        Self { p: point, x: value }
+    }
+
+    /// Returns the point at which the density is defined.
+    pub fn get_point(&self) -> &Point<T> {
+        &self.p
+    }
+
+    /// Returns the value of the density.
+    pub fn value(&self) -> f64 {
+        self.x
+    }
+
+    /// Converts the density to the system `T2`, dividing its value by the absolute value
+    /// of the determinant of the Jacobian ∂x'/∂x of the conversion (see
+    /// `ConversionTo::jacobian_determinant`).
+    pub fn convert<T2>(&self) -> Density<T2>
+    where
+        T2: CoordinateSystem + 'static,
+        T2::Dimension: Pow<U2>,
+        Exp<T2::Dimension, U2>: ArrayLength<f64>,
+        T: ConversionTo<T2>,
+    {
+        let det = <T as ConversionTo<T2>>::jacobian_determinant(&self.p);
+        Density::new(T::convert_point(&self.p), self.x * weight_factor(det, 1))
+    }
+}
+
+impl<T> Clone for Density<T>
+where
+    T: CoordinateSystem,
+{
+    fn clone(&self) -> Self {
+        Self {
+            p: self.p.clone(),
+            x: self.x,
+        }
+    }
+}
+
+impl<T> Copy for Density<T>
+where
+    T: CoordinateSystem,
+    <T::Dimension as ArrayLength<f64>>::ArrayType: Copy,
+{
+}
+
+impl<T> fmt::Debug for Density<T>
+where
+    T: CoordinateSystem,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Density({:?}, {:?})", &self.p, self.x)
+    }
+}
+
+/// Struct representing a tensor density of an arbitrary integer weight w: a tensor whose
+/// conversion x → x' is additionally multiplied by |det(∂x/∂x')|^w.
+///
+/// Weight 0 corresponds to an ordinary tensor, and a scalar of weight 1 to a `Density`. For
+/// example, the Levi-Civita symbol with lower indices (having the components ±1 and 0 in
+/// every coordinate system) is a density of weight -1, and the one with upper indices has
+/// weight 1. Since the absolute value of the determinant is used, conversions reversing
+/// the orientation don't change the signs.
+pub struct TensorDensity<T: CoordinateSystem, V: Variance>
+where
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    tensor: Tensor<T, V>,
+    weight: i32,
+}
+
+impl<T, V> TensorDensity<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Creates a tensor density of the given weight with the components of the tensor.
+    pub fn new(tensor: Tensor<T, V>, weight: i32) -> Self {
+        Self { tensor, weight }
+    }
+
+    /// Returns the weight of the density.
+    pub fn weight(&self) -> i32 {
+        self.weight
+    }
+
+    /// Returns the components of the density, as a tensor.
+    pub fn tensor(&self) -> &Tensor<T, V> {
+        &self.tensor
+    }
+
+    /// Returns the components of the density as a tensor, dropping the weight.
+    pub fn into_tensor(self) -> Tensor<T, V> {
+        self.tensor
+    }
+
+    /// Converts the density to the system `T2`, converting the components like a tensor
+    /// with `Tensor::convert` and multiplying them by |det(∂x/∂x')|^w, i.e. dividing by the
+    /// w-th power of the absolute value of `ConversionTo::jacobian_determinant`.
+    pub fn convert<T2>(&self) -> TensorDensity<T2, V>
+    where
+        V::Rank: ArrayLength<usize> + ArrayLength<IndexType>,
+        T2: CoordinateSystem + 'static,
+        T2::Dimension: Pow<V::Rank> + Pow<U2> + Same<T::Dimension>,
+        Exp<T2::Dimension, V::Rank>: ArrayLength<f64>,
+        Exp<T2::Dimension, U2>: ArrayLength<f64>,
+        T: ConversionTo<T2>,
+    {
+        let mut tensor = self.tensor.convert::<T2>();
+        if self.weight != 0 {
+            let det = <T as ConversionTo<T2>>::jacobian_determinant(self.tensor.get_point());
+            tensor *= weight_factor(det, self.weight);
+        }
+        TensorDensity::new(tensor, self.weight)
+    }
+}
+
+impl<T, V> Clone for TensorDensity<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tensor: self.tensor.clone(),
+            weight: self.weight,
+        }
+    }
+}
+
+impl<T, V> fmt::Debug for TensorDensity<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "TensorDensity({:?}, weight {})",
+            &self.tensor, self.weight
+        )
+    }
+}
@@ -0,0 +1,381 @@
+//! This module implements contractions of tensors described by subscript strings, in the
+//! style of numpy's `einsum` - e.g. "ij,jk->ik" for the matrix product.
+//!
+//! Every operand is described by one letter per index, and the output, after "->", lists
+//! the indices which are kept. The indices appearing in the operands but not in the output
+//! are summed over.
+
+use super::{IndexType, Tensor, TensorError, Variance};
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::typenum::{Exp, Pow};
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use generic_array::ArrayLength;
+
+// The parsed subscripts: the labels of the indices of the operands and of the output
+struct Subscripts {
+    operands: Vec<Vec<u8>>,
+    output: Vec<u8>,
+}
+
+impl Subscripts {
+    fn parse(subscripts: &str) -> Result<Self, TensorError> {
+        let error = |reason| TensorError::InvalidSubscripts {
+            subscripts: subscripts.to_string(),
+            reason,
+        };
+        let labels = |part: &str| -> Result<Vec<u8>, TensorError> {
+            let part = part.trim();
+            if part.bytes().all(|c| c.is_ascii_alphabetic()) {
+                Ok(part.bytes().collect())
+            } else {
+                Err(error("the indices must be labeled by letters"))
+            }
+        };
+
+        let mut parts = subscripts.split("->");
+        let (inputs, output) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(inputs), Some(output), None) => (inputs, output),
+            _ => return Err(error("expected exactly one \"->\"")),
+        };
+        let operands = inputs
+            .split(',')
+            .map(labels)
+            .collect::<Result<Vec<_>, _>>()?;
+        let output = labels(output)?;
+
+        for (k, &label) in output.iter().enumerate() {
+            if output[..k].contains(&label) {
+                return Err(error("an output index is listed more than once"));
+            }
+            if !operands.iter().any(|op| op.contains(&label)) {
+                return Err(error("an output index doesn't appear in the operands"));
+            }
+        }
+        Ok(Self { operands, output })
+    }
+}
+
+// Returns the distinct labels appearing in the given lists
+fn distinct(lists: &[&[u8]]) -> Vec<u8> {
+    let mut result = vec![];
+    for &label in lists.iter().flat_map(|list| list.iter()) {
+        if !result.contains(&label) {
+            result.push(label);
+        }
+    }
+    result
+}
+
+/// The largest number of operands accepted by `plan_contraction`. There are 2700 orders of
+/// contracting 6 operands, and every additional operand multiplies that by over 20.
+pub const MAX_PLANNED_OPERANDS: usize = 6;
+
+/// A suggested order of pairwise contractions for evaluating an expression, as returned by
+/// `plan_contraction`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ContractionPlan {
+    /// The pairs of operands to contract, as positions in the current list of operands:
+    /// after each step, the two operands are removed from the list and the result of
+    /// their contraction is appended at its end.
+    pub steps: Vec<(usize, usize)>,
+    /// The number of multiply-adds needed when following the steps.
+    pub cost: usize,
+}
+
+// Returns the number of values of the given number of indices, saturating at `usize::MAX`
+fn combinations(dimension: usize, indices: usize) -> usize {
+    dimension.saturating_pow(indices as u32)
+}
+
+/// Returns the number of multiply-adds needed for evaluating the expression described by
+/// the subscripts directly, i.e. by multiplying all the operands together for every
+/// combination of the values of the indices, in a space of the given dimension.
+///
+/// With n operands and L distinct index labels, this is d^L (n - 1) for n > 1, and d^L for
+/// a single operand (e.g. a trace). Costs too large for a `usize` saturate at `usize::MAX`.
+pub fn contraction_cost(subscripts: &str, dimension: usize) -> Result<usize, TensorError> {
+    let parsed = Subscripts::parse(subscripts)?;
+    let lists: Vec<&[u8]> = parsed.operands.iter().map(|op| &op[..]).collect();
+    let terms = combinations(dimension, distinct(&lists).len());
+    Ok(terms.saturating_mul((parsed.operands.len() - 1).max(1)))
+}
+
+/// Returns the number of multiply-adds needed for evaluating the expression described by
+/// the subscripts by contracting pairs of operands in the order given by `steps` (in the
+/// same format as `ContractionPlan::steps`), in a space of the given dimension.
+///
+/// Contracting two operands costs d^L, where L is the number of distinct index labels in
+/// them; the intermediate result keeps the indices needed by the other operands or the
+/// output. If the last remaining operand still has indices to sum over (as in a trace),
+/// this costs d^L as well. Like in `contraction_cost`, the cost saturates at `usize::MAX`.
+/// An error is returned if a step refers to nonexistent operands, or if the steps don't
+/// reduce the operands to a single one.
+pub fn path_cost(
+    subscripts: &str,
+    dimension: usize,
+    steps: &[(usize, usize)],
+) -> Result<usize, TensorError> {
+    let Subscripts {
+        mut operands,
+        output,
+    } = Subscripts::parse(subscripts)?;
+    let error = |reason| TensorError::InvalidSubscripts {
+        subscripts: subscripts.to_string(),
+        reason,
+    };
+
+    let mut cost = 0;
+    for &(i, j) in steps {
+        if i == j || i >= operands.len() || j >= operands.len() {
+            return Err(error("invalid contraction step"));
+        }
+        let (a, b) = (operands[i].clone(), operands[j].clone());
+        let (first, second) = (i.max(j), i.min(j));
+        operands.remove(first);
+        operands.remove(second);
+
+        let all = distinct(&[&a, &b]);
+        cost = combinations(dimension, all.len()).saturating_add(cost);
+        let kept = all
+            .into_iter()
+            .filter(|l| output.contains(l) || operands.iter().any(|op| op.contains(l)))
+            .collect();
+        operands.push(kept);
+    }
+
+    if operands.len() != 1 {
+        return Err(error("the steps don't reduce the operands to one"));
+    }
+    let last = distinct(&[&operands[0]]);
+    if last.len() != operands[0].len() || last.iter().any(|l| !output.contains(l)) {
+        cost = combinations(dimension, last.len()).saturating_add(cost);
+    }
+    Ok(cost)
+}
+
+/// Finds the order of pairwise contractions with the lowest cost (as calculated by
+/// `path_cost`) for evaluating the expression described by the subscripts, in a space of
+/// the given dimension.
+///
+/// All the orders are tried, so the time taken grows very quickly with the number of
+/// operands; this is meant for expressions with a handful of them, for which a bad order
+/// can easily be orders of magnitude slower than the best one. An error is returned for
+/// expressions with more than `MAX_PLANNED_OPERANDS` operands.
+pub fn plan_contraction(
+    subscripts: &str,
+    dimension: usize,
+) -> Result<ContractionPlan, TensorError> {
+    let n = Subscripts::parse(subscripts)?.operands.len();
+    if n > MAX_PLANNED_OPERANDS {
+        return Err(TensorError::InvalidSubscripts {
+            subscripts: subscripts.to_string(),
+            reason: "too many operands to try all the contraction orders",
+        });
+    }
+
+    // all the sequences of steps reducing n operands to one
+    fn paths(n: usize) -> Vec<Vec<(usize, usize)>> {
+        if n <= 1 {
+            return vec![vec![]];
+        }
+        let rest = paths(n - 1);
+        let mut result = vec![];
+        for i in 0..n {
+            for j in i + 1..n {
+                for steps in &rest {
+                    let mut path = vec![(i, j)];
+                    path.extend_from_slice(steps);
+                    result.push(path);
+                }
+            }
+        }
+        result
+    }
+
+    let mut best: Option<ContractionPlan> = None;
+    for steps in paths(n) {
+        let cost = path_cost(subscripts, dimension, &steps)?;
+        if best.as_ref().is_none_or(|plan| cost < plan.cost) {
+            best = Some(ContractionPlan { steps, cost });
+        }
+    }
+    Ok(best.unwrap())
+}
+
+// Checks that the subscripts match the operands and the result, and that every summed index
+// pairs an upper index with a lower one - so that the result doesn't depend on the
+// coordinate system.
+fn validate(
+    subscripts: &str,
+    parsed: &Subscripts,
+    operands: &[Vec<IndexType>],
+    output: &[IndexType],
+) -> Result<(), TensorError> {
+    let error = |reason| TensorError::InvalidSubscripts {
+        subscripts: subscripts.to_string(),
+        reason,
+    };
+    if parsed.operands.len() != operands.len() {
+        return Err(error("wrong number of operands"));
+    }
+    if parsed
+        .operands
+        .iter()
+        .zip(operands)
+        .any(|(labels, variance)| labels.len() != variance.len())
+        || parsed.output.len() != output.len()
+    {
+        return Err(error("the number of indices doesn't match the rank"));
+    }
+
+    let lists: Vec<&[u8]> = parsed.operands.iter().map(|op| &op[..]).collect();
+    for label in distinct(&lists) {
+        let occurrences: Vec<IndexType> = parsed
+            .operands
+            .iter()
+            .zip(operands)
+            .flat_map(|(labels, variance)| labels.iter().zip(variance))
+            .filter(|&(&l, _)| l == label)
+            .map(|(_, &index_type)| index_type)
+            .collect();
+        match parsed.output.iter().position(|&l| l == label) {
+            Some(k) if occurrences == [output[k]] => {}
+            Some(_) => {
+                return Err(error(
+                    "an output index must appear once in the operands, with the same variance",
+                ))
+            }
+            None if occurrences.len() == 2 && occurrences[0] != occurrences[1] => {}
+            None => {
+                return Err(error(
+                    "a summed index must appear twice, once as upper and once as lower",
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+// Returns the strides of an index list with the given labels, for every label in `all`
+// (zero for the labels absent from the list)
+fn label_strides(labels: &[u8], all: &[u8], dimension: usize) -> Vec<usize> {
+    all.iter()
+        .map(|label| {
+            labels
+                .iter()
+                .enumerate()
+                .filter(|&(_, l)| l == label)
+                .map(|(k, _)| dimension.pow((labels.len() - 1 - k) as u32))
+                .sum()
+        })
+        .collect()
+}
+
+// Evaluates the validated expression by going through all the combinations of the values of
+// the indices, accumulating the products of the operands' coordinates into the result
+fn evaluate(parsed: &Subscripts, operands: &[&[f64]], dimension: usize) -> Vec<f64> {
+    let lists: Vec<&[u8]> = parsed.operands.iter().map(|op| &op[..]).collect();
+    let all = distinct(&lists);
+    let strides: Vec<Vec<usize>> = parsed
+        .operands
+        .iter()
+        .map(|labels| label_strides(labels, &all, dimension))
+        .collect();
+    let output_strides = label_strides(&parsed.output, &all, dimension);
+
+    let mut result = vec![0.0; combinations(dimension, parsed.output.len())];
+    let mut values = vec![0; all.len()];
+    for _ in 0..combinations(dimension, all.len()) {
+        let position =
+            |strides: &[usize]| -> usize { values.iter().zip(strides).map(|(v, s)| v * s).sum() };
+        let product: f64 = operands
+            .iter()
+            .zip(&strides)
+            .map(|(x, strides)| x[position(strides)])
+            .product();
+        result[position(&output_strides)] += product;
+
+        for v in values.iter_mut().rev() {
+            *v += 1;
+            if *v < dimension {
+                break;
+            }
+            *v = 0;
+        }
+    }
+    result
+}
+
+/// Evaluates the contraction of two tensors described by the subscripts, e.g. "ij,jk->ik"
+/// for the product of two matrices, or "i,j->ij" for the outer product of two vectors.
+///
+/// The subscripts are checked against the tensors at runtime: the number of indices of
+/// each operand and of the output must match their ranks, every index kept in the output
+/// must appear once in the operands with the same variance, and every other index must
+/// appear twice - once as an upper index and once as a lower one, just as in the typed
+/// contractions. The tensors must be defined at the same point.
+pub fn einsum<T, V1, V2, W>(
+    subscripts: &str,
+    a: &Tensor<T, V1>,
+    b: &Tensor<T, V2>,
+) -> Result<Tensor<T, W>, TensorError>
+where
+    T: CoordinateSystem,
+    V1: Variance,
+    V2: Variance,
+    W: Variance,
+    T::Dimension: Pow<V1::Rank> + Pow<V2::Rank> + Pow<W::Rank>,
+    Exp<T::Dimension, V1::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, V2::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, W::Rank>: ArrayLength<f64>,
+{
+    let parsed = Subscripts::parse(subscripts)?;
+    validate(
+        subscripts,
+        &parsed,
+        &[V1::variance(), V2::variance()],
+        &W::variance(),
+    )?;
+    check_point(a.get_point(), b.get_point())?;
+    let result = evaluate(
+        &parsed,
+        &[a.coords_array(), b.coords_array()],
+        T::dimension(),
+    );
+    Ok(Tensor::from_slice(a.get_point().clone(), &result))
+}
+
+/// Evaluates an expression with a single tensor described by the subscripts, e.g. "ii->"
+/// for the trace of a matrix, or "ij->ji" for its transposition.
+///
+/// The subscripts are checked in the same way as in `einsum`.
+pub fn einsum_single<T, V, W>(
+    subscripts: &str,
+    a: &Tensor<T, V>,
+) -> Result<Tensor<T, W>, TensorError>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    W: Variance,
+    T::Dimension: Pow<V::Rank> + Pow<W::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, W::Rank>: ArrayLength<f64>,
+{
+    let parsed = Subscripts::parse(subscripts)?;
+    validate(subscripts, &parsed, &[V::variance()], &W::variance())?;
+    let result = evaluate(&parsed, &[a.coords_array()], T::dimension());
+    Ok(Tensor::from_slice(a.get_point().clone(), &result))
+}
+
+fn check_point<T: CoordinateSystem>(left: &Point<T>, right: &Point<T>) -> Result<(), TensorError> {
+    if left.approx_eq(right) {
+        Ok(())
+    } else {
+        Err(TensorError::PointMismatch {
+            left: left.coords_array().to_vec(),
+            right: right.coords_array().to_vec(),
+        })
+    }
+}
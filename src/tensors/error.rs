@@ -0,0 +1,71 @@
+//! This module defines the error type returned by the fallible operations on tensors.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+/// An error returned by the fallible operations on tensors.
+#[derive(Clone, PartialEq, Debug)]
+pub enum TensorError {
+    /// The number of the given coordinates doesn't match the number of coordinates of the
+    /// tensor.
+    WrongLength { expected: usize, found: usize },
+    /// The coordinate at the given position in the internal array is NaN or infinite.
+    NonFinite { index: usize },
+    /// The coordinate system is singular at the point with the given coordinates, so the
+    /// tensor can't be converted.
+    CoordinateSingularity { point: Vec<f64> },
+    /// The tensors are defined at different points, with the given coordinates, so they
+    /// can't be combined.
+    PointMismatch { left: Vec<f64>, right: Vec<f64> },
+    /// The tensor has no index at the given position, or it has the wrong variance for
+    /// the operation.
+    InvalidIndex { index: usize },
+    /// The index at the given position is listed more than once.
+    DuplicateIndex { index: usize },
+    /// The subscripts describing a contraction (see `einsum`) are malformed or don't match
+    /// the operands, for the given reason.
+    InvalidSubscripts {
+        subscripts: String,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for TensorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TensorError::WrongLength { expected, found } => {
+                write!(f, "expected {} coordinates, found {}", expected, found)
+            }
+            TensorError::NonFinite { index } => {
+                write!(f, "the coordinate at index {} is not finite", index)
+            }
+            TensorError::CoordinateSingularity { point } => {
+                write!(f, "coordinate singularity at {:?}", point)
+            }
+            TensorError::PointMismatch { left, right } => {
+                write!(
+                    f,
+                    "the tensors are defined at different points: {:?} and {:?}",
+                    left, right
+                )
+            }
+            TensorError::InvalidIndex { index } => {
+                write!(f, "invalid index at position {}", index)
+            }
+            TensorError::DuplicateIndex { index } => {
+                write!(
+                    f,
+                    "the index at position {} is listed more than once",
+                    index
+                )
+            }
+            TensorError::InvalidSubscripts { subscripts, reason } => {
+                write!(f, "invalid subscripts \"{}\": {}", subscripts, reason)
+            }
+        }
+    }
+}
+
+impl Error for TensorError {}
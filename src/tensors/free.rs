@@ -0,0 +1,235 @@
+//! This module defines the `FreeTensor` type, representing tensors not anchored at any point.
+
+use super::kernels;
+use super::variance::{Concat, Joined};
+use super::{Tensor, Variance};
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::typenum::{Exp, Pow};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Index, IndexMut, Mul, Sub};
+use generic_array::{ArrayLength, GenericArray};
+
+/// Struct representing a tensor which isn't anchored at any point.
+///
+/// It supports pure component algebra - addition, multiplication by scalars and outer
+/// products - without the need to carry a (possibly dummy) point around. Once the
+/// calculation is done, the result can be anchored at a point with `anchor`, which yields
+/// a regular `Tensor`.
+pub struct FreeTensor<T: CoordinateSystem, V: Variance>
+where
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    x: GenericArray<f64, Exp<T::Dimension, V::Rank>>,
+    _system: PhantomData<T>,
+}
+
+impl<T, V> FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Creates a free tensor with the coordinates defined by the array, in the same order
+    /// as in `Tensor::new`.
+    pub fn new(coords: GenericArray<f64, Exp<T::Dimension, V::Rank>>) -> Self {
+        Self {
+            x: coords,
+            _system: PhantomData,
+        }
+    }
+
+    /// Creates a free tensor with the coordinates passed in the slice, in the same order as
+    /// in `Tensor::from_slice`.
+    pub fn from_slice(slice: &[f64]) -> Self {
+        assert_eq!(Tensor::<T, V>::get_num_coords(), slice.len());
+        Self::new(GenericArray::clone_from_slice(slice))
+    }
+
+    /// Creates a zero free tensor
+    pub fn zero() -> Self {
+        Self::new(<_>::default())
+    }
+
+    /// Returns the tensor's coordinates as an array
+    pub fn coords_array(&self) -> &GenericArray<f64, Exp<T::Dimension, V::Rank>> {
+        &self.x
+    }
+
+    /// Anchors the tensor at a given point, turning it into a regular `Tensor`.
+    pub fn anchor(self, point: Point<T>) -> Tensor<T, V> {
+        Tensor::new(point, self.x)
+    }
+}
+
+impl<T, V> Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Detaches the tensor from its point, keeping only the coordinates.
+    pub fn into_free(self) -> FreeTensor<T, V> {
+        FreeTensor::new(self.coords_array().clone())
+    }
+}
+
+impl<T, V> Clone for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.x.clone())
+    }
+}
+
+impl<T, V> Copy for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    <Exp<T::Dimension, V::Rank> as ArrayLength<f64>>::ArrayType: Copy,
+{
+}
+
+impl<T, V> fmt::Debug for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FreeTensor{:?}", &self.x)
+    }
+}
+
+impl<T, V> Index<usize> for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    type Output = f64;
+
+    fn index(&self, idx: usize) -> &f64 {
+        &self.x[idx]
+    }
+}
+
+impl<T, V> IndexMut<usize> for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    fn index_mut(&mut self, idx: usize) -> &mut f64 {
+        &mut self.x[idx]
+    }
+}
+
+impl<'a, T, V> Index<&'a [usize]> for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    type Output = f64;
+
+    fn index(&self, idx: &'a [usize]) -> &f64 {
+        &self.x[Tensor::<T, V>::get_coord(idx)]
+    }
+}
+
+impl<'a, T, V> IndexMut<&'a [usize]> for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    fn index_mut(&mut self, idx: &'a [usize]) -> &mut f64 {
+        &mut self.x[Tensor::<T, V>::get_coord(idx)]
+    }
+}
+
+impl<T, V> Add for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        kernels::zip_apply(&mut self.x, &rhs.x, |a, b| *a += b);
+        self
+    }
+}
+
+impl<T, V> Sub for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        kernels::zip_apply(&mut self.x, &rhs.x, |a, b| *a -= b);
+        self
+    }
+}
+
+impl<T, V> Mul<f64> for FreeTensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    type Output = Self;
+
+    fn mul(mut self, rhs: f64) -> Self {
+        kernels::apply(&mut self.x, |a| *a *= rhs);
+        self
+    }
+}
+
+impl<T, U, V> Mul<FreeTensor<T, V>> for FreeTensor<T, U>
+where
+    T: CoordinateSystem,
+    U: Variance,
+    V: Variance,
+    T::Dimension: Pow<U::Rank> + Pow<V::Rank>,
+    Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    U: Concat<V>,
+    Joined<U, V>: Variance,
+    T::Dimension: Pow<<Joined<U, V> as Variance>::Rank>,
+    Exp<T::Dimension, <Joined<U, V> as Variance>::Rank>: ArrayLength<f64>,
+{
+    type Output = FreeTensor<T, Joined<U, V>>;
+
+    fn mul(self, rhs: FreeTensor<T, V>) -> Self::Output {
+        let mut result = FreeTensor::<T, Joined<U, V>>::zero();
+        let num_coords2 = Tensor::<T, V>::get_num_coords();
+        let num_coords_result = Tensor::<T, Joined<U, V>>::get_num_coords();
+        for coord in 0..num_coords_result {
+            result[coord] = self[coord / num_coords2] * rhs[coord % num_coords2];
+        }
+        result
+    }
+}
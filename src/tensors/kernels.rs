@@ -0,0 +1,52 @@
+//! Component-wise kernels used by the arithmetic operations on tensors, and `Powers`, a
+//! table of the powers of the dimension used for calculating strides in contractions.
+
+use core::ops::Index;
+
+/// Applies `f` to every pair of corresponding components of `lhs` and `rhs`.
+#[inline(always)]
+pub fn zip_apply<F: Fn(&mut f64, f64)>(lhs: &mut [f64], rhs: &[f64], f: F) {
+    for (a, b) in lhs.iter_mut().zip(rhs) {
+        f(a, *b);
+    }
+}
+
+/// Applies `f` to every component of `lhs`.
+#[inline(always)]
+pub fn apply<F: Fn(&mut f64)>(lhs: &mut [f64], f: F) {
+    for a in lhs.iter_mut() {
+        f(a);
+    }
+}
+
+/// The number of powers stored in `Powers`. Tensors with a rank this large would have at
+/// least 2^32 coordinates, so it is never exceeded in practice.
+const MAX_POWERS: usize = 32;
+
+/// A table of the powers base^0, base^1, ..., computed once by repeated multiplication, so
+/// that the strides of the indices don't have to be recalculated with `pow`.
+#[derive(Clone, Copy, Debug)]
+pub struct Powers([usize; MAX_POWERS]);
+
+impl Powers {
+    /// Creates the table of the powers of `base` with exponents less than `count`.
+    pub fn new(base: usize, count: usize) -> Self {
+        assert!(count <= MAX_POWERS);
+        let mut result = [0; MAX_POWERS];
+        let mut power = 1;
+        for x in result.iter_mut().take(count) {
+            *x = power;
+            power *= base;
+        }
+        Powers(result)
+    }
+}
+
+impl Index<usize> for Powers {
+    type Output = usize;
+
+    #[inline(always)]
+    fn index(&self, exponent: usize) -> &usize {
+        &self.0[exponent]
+    }
+}
@@ -0,0 +1,749 @@
+//! This module contains dense linear algebra routines used for treating rank 2
+//! tensors as matrices.
+//!
+//! The routines at the top of the module work on plain n x n matrices stored row by
+//! row in slices, in the same order as the coordinates of a rank 2 tensor. The
+//! functions exposed on `Tensor` are defined at the bottom.
+
+pub(crate) use super::lu::inverse;
+use super::{
+    ContravariantIndex, CovariantIndex, Matrix, OtherIndex, Tensor, TensorIndex, Variance, Vector,
+};
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::typenum::{
+    consts::{B1, U1, U2},
+    uint::Unsigned,
+    Add1, Exp, Pow,
+};
+use generic_array::{ArrayLength, GenericArray};
+use std::ops::Add;
+
+// Returns the n x n identity matrix
+pub(crate) fn identity(n: usize) -> Vec<f64> {
+    let mut result = vec![0.0; n * n];
+    for i in 0..n {
+        result[i * n + i] = 1.0;
+    }
+    result
+}
+
+// Calculates the product of two n x n matrices
+pub(crate) fn matmul(a: &[f64], b: &[f64], n: usize) -> Vec<f64> {
+    let mut result = vec![0.0; n * n];
+    for i in 0..n {
+        for k in 0..n {
+            let aik = a[i * n + k];
+            for j in 0..n {
+                result[i * n + j] += aik * b[k * n + j];
+            }
+        }
+    }
+    result
+}
+
+// Returns the transpose of an n x n matrix
+pub(crate) fn transpose(a: &[f64], n: usize) -> Vec<f64> {
+    let mut result = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..n {
+            result[j * n + i] = a[i * n + j];
+        }
+    }
+    result
+}
+
+// Calculates a * s + b * t component-wise
+fn lin_comb(a: &[f64], s: f64, b: &[f64], t: f64) -> Vec<f64> {
+    a.iter().zip(b).map(|(x, y)| x * s + y * t).collect()
+}
+
+// The maximum absolute row sum norm
+pub(crate) fn norm_inf(a: &[f64], n: usize) -> f64 {
+    a.chunks(n)
+        .map(|row| row.iter().map(|x| x.abs()).sum())
+        .fold(0.0, f64::max)
+}
+
+// Checks whether the matrix is symmetric, up to a tolerance relative to its norm
+pub(crate) fn is_symmetric(a: &[f64], n: usize) -> bool {
+    let tol = 1e-12 * norm_inf(a, n).max(1.0);
+    (0..n).all(|i| (0..i).all(|j| (a[i * n + j] - a[j * n + i]).abs() <= tol))
+}
+
+// Calculates the QR decomposition using Householder reflections. Returns (Q, R), where Q is
+// orthogonal and R upper triangular.
+pub(crate) fn qr(a: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut q = identity(n);
+    let mut r = a.to_vec();
+
+    for k in 0..n.saturating_sub(1) {
+        // the reflection maps the k-th column below the diagonal to a multiple of e_k
+        let norm = (k..n)
+            .map(|i| r[i * n + k] * r[i * n + k])
+            .sum::<f64>()
+            .sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let alpha = if r[k * n + k] > 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = (k..n).map(|i| r[i * n + k]).collect();
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if v_norm == 0.0 {
+            continue;
+        }
+        v.iter_mut().for_each(|x| *x /= v_norm);
+
+        // R = H R, Q = Q H, where H = I - 2 v v^T acts on the coordinates k..n
+        for j in 0..n {
+            let dot: f64 = (k..n).map(|i| v[i - k] * r[i * n + j]).sum();
+            for i in k..n {
+                r[i * n + j] -= 2.0 * v[i - k] * dot;
+            }
+        }
+        for i in 0..n {
+            let dot: f64 = (k..n).map(|j| q[i * n + j] * v[j - k]).sum();
+            for j in k..n {
+                q[i * n + j] -= 2.0 * dot * v[j - k];
+            }
+        }
+    }
+
+    (q, r)
+}
+
+// Calculates the eigendecomposition of a symmetric matrix using the cyclic Jacobi method.
+// Returns the eigenvalues and a matrix having the corresponding eigenvectors as columns.
+pub(crate) fn symmetric_eigen(a: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut a = a.to_vec();
+    let mut v = identity(n);
+    let norm: f64 = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i * n + j] * a[i * n + j])
+            .sum::<f64>()
+            .sqrt();
+        if off <= 1e-15 * norm {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq == 0.0 {
+                    continue;
+                }
+                let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let t = if theta == 0.0 { 1.0 } else { t };
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                // a = J^T a J, where J is the rotation in the p-q plane
+                for k in 0..n {
+                    let akp = a[k * n + p];
+                    let akq = a[k * n + q];
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let apk = a[p * n + k];
+                    let aqk = a[q * n + k];
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let vkp = v[k * n + p];
+                    let vkq = v[k * n + q];
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    ((0..n).map(|i| a[i * n + i]).collect(), v)
+}
+
+// Calculates the singular value decomposition A = U Σ V^T using the one-sided Jacobi method.
+// Returns (U, σ, V^T), where U and V are orthogonal and the singular values σ are sorted in
+// decreasing order.
+pub(crate) fn svd(a: &[f64], n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let mut u = a.to_vec();
+    let mut v = identity(n);
+
+    // orthogonalize the columns of U = A V by rotations in the planes of pairs of columns
+    for _ in 0..100 {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                for k in 0..n {
+                    alpha += u[k * n + p] * u[k * n + p];
+                    beta += u[k * n + q] * u[k * n + q];
+                    gamma += u[k * n + p] * u[k * n + q];
+                }
+                if gamma.abs() <= 1e-15 * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (zeta * zeta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for m in [&mut u, &mut v] {
+                    for k in 0..n {
+                        let mkp = m[k * n + p];
+                        let mkq = m[k * n + q];
+                        m[k * n + p] = c * mkp - s * mkq;
+                        m[k * n + q] = s * mkp + c * mkq;
+                    }
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let sigma: Vec<f64> = (0..n)
+        .map(|j| {
+            (0..n)
+                .map(|k| u[k * n + j] * u[k * n + j])
+                .sum::<f64>()
+                .sqrt()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| sigma[j].total_cmp(&sigma[i]));
+    let max = sigma.iter().cloned().fold(0.0, f64::max);
+
+    let mut u_sorted = vec![0.0; n * n];
+    let mut vt = vec![0.0; n * n];
+    let mut sigma_sorted = vec![0.0; n];
+    for (j, &o) in order.iter().enumerate() {
+        sigma_sorted[j] = sigma[o];
+        for k in 0..n {
+            vt[j * n + k] = v[k * n + o];
+        }
+        // the columns for (numerically) vanishing singular values are filled in below
+        if sigma[o] > 1e-14 * max {
+            for k in 0..n {
+                u_sorted[k * n + j] = u[k * n + o] / sigma[o];
+            }
+        }
+    }
+
+    // complete U to an orthogonal matrix with the Gram-Schmidt process on the standard basis:
+    // of the basis vectors with the other columns projected out (twice, for stability), the
+    // longest one is used. The squared lengths sum up to the number of missing columns, so it
+    // is never shorter than 1 / sqrt(n).
+    for j in 0..n {
+        if sigma_sorted[j] > 1e-14 * max {
+            continue;
+        }
+        let residual = |i: usize| {
+            let mut w = vec![0.0; n];
+            w[i] = 1.0;
+            for _ in 0..2 {
+                for l in (0..n).filter(|&l| l != j) {
+                    let dot: f64 = (0..n).map(|k| u_sorted[k * n + l] * w[k]).sum();
+                    for k in 0..n {
+                        w[k] -= dot * u_sorted[k * n + l];
+                    }
+                }
+            }
+            let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+            (w, norm)
+        };
+        let (w, norm) = (0..n)
+            .map(residual)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+        for k in 0..n {
+            u_sorted[k * n + j] = w[k] / norm;
+        }
+    }
+
+    (u_sorted, sigma_sorted, vt)
+}
+
+// Calculates V * diag(f(λ)) * V^T for a symmetric matrix with eigenvalues λ and
+// eigenvectors V
+pub(crate) fn symmetric_function<F: Fn(f64) -> f64>(
+    values: &[f64],
+    vectors: &[f64],
+    n: usize,
+    f: F,
+) -> Vec<f64> {
+    let mut result = vec![0.0; n * n];
+    for (k, &value) in values.iter().enumerate() {
+        let fk = f(value);
+        for i in 0..n {
+            for j in 0..n {
+                result[i * n + j] += vectors[i * n + k] * fk * vectors[j * n + k];
+            }
+        }
+    }
+    result
+}
+
+// Calculates the matrix exponential using scaling and squaring with a [6/6] Padé approximant
+pub(crate) fn expm(a: &[f64], n: usize) -> Vec<f64> {
+    const Q: usize = 6;
+
+    let norm = norm_inf(a, n);
+    let squarings = if norm > 0.5 {
+        (norm / 0.5).log2().ceil() as i32
+    } else {
+        0
+    };
+    let scaled: Vec<f64> = a.iter().map(|x| x / 2.0_f64.powi(squarings)).collect();
+
+    let mut c = 1.0;
+    let mut power = identity(n);
+    let mut num = identity(n);
+    let mut den = identity(n);
+    for k in 1..=Q {
+        c *= (Q - k + 1) as f64 / (k * (2 * Q - k + 1)) as f64;
+        power = matmul(&power, &scaled, n);
+        let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+        num = lin_comb(&num, 1.0, &power, c);
+        den = lin_comb(&den, 1.0, &power, sign * c);
+    }
+
+    // This can't fail for a finite matrix: after scaling, ‖A‖ ≤ 1/2, and the denominator
+    // is I + B with ‖B‖ ≤ Σ c_k / 2^k < 0.3, so it is invertible (by the Neumann series)
+    // and well conditioned.
+    let mut result = matmul(&inverse(&den, n).unwrap(), &num, n);
+    for _ in 0..squarings {
+        result = matmul(&result, &result, n);
+    }
+    result
+}
+
+// Calculates the principal square root using the Denman-Beavers iteration.
+// Returns None if the iteration doesn't converge.
+pub(crate) fn sqrtm(a: &[f64], n: usize) -> Option<Vec<f64>> {
+    let mut y = a.to_vec();
+    let mut z = identity(n);
+
+    for _ in 0..100 {
+        let y_inv = inverse(&y, n)?;
+        let z_inv = inverse(&z, n)?;
+        let new_y = lin_comb(&y, 0.5, &z_inv, 0.5);
+        let new_z = lin_comb(&z, 0.5, &y_inv, 0.5);
+        let diff = norm_inf(&lin_comb(&new_y, 1.0, &y, -1.0), n);
+        y = new_y;
+        z = new_z;
+        if diff <= 1e-14 * norm_inf(&y, n) {
+            return Some(y);
+        }
+    }
+
+    None
+}
+
+// Calculates the principal logarithm using inverse scaling and squaring: square roots are
+// taken until the matrix is close to the identity, and then the Taylor series of
+// log(I + X) is used. Returns None if the logarithm couldn't be calculated.
+pub(crate) fn logm(a: &[f64], n: usize) -> Option<Vec<f64>> {
+    let id = identity(n);
+    let mut a = a.to_vec();
+    let mut roots = 0;
+
+    while norm_inf(&lin_comb(&a, 1.0, &id, -1.0), n) > 0.25 {
+        if roots == 64 {
+            return None;
+        }
+        a = sqrtm(&a, n)?;
+        roots += 1;
+    }
+
+    // ‖X‖ <= 0.25, so 30 terms are enough for double precision
+    let x = lin_comb(&a, 1.0, &id, -1.0);
+    let mut power = x.clone();
+    let mut result = vec![0.0; n * n];
+    for m in 1..=30 {
+        let sign = if m % 2 == 0 { -1.0 } else { 1.0 };
+        result = lin_comb(&result, 1.0, &power, sign / m as f64);
+        power = matmul(&power, &x, n);
+    }
+
+    let factor = 2.0_f64.powi(roots);
+    Some(result.iter().map(|x| x * factor).collect())
+}
+
+// Calculates the n-th power of a matrix using exponentiation by squaring
+pub(crate) fn powm(a: &[f64], mut exp: u32, n: usize) -> Vec<f64> {
+    let mut base = a.to_vec();
+    let mut result = identity(n);
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = matmul(&result, &base, n);
+        }
+        exp /= 2;
+        if exp > 0 {
+            base = matmul(&base, &base, n);
+        }
+    }
+    result
+}
+
+impl<T, Ul, Ur> Tensor<T, (Ul, Ur)>
+where
+    T: CoordinateSystem,
+    Ul: TensorIndex + OtherIndex,
+    Ur: TensorIndex + OtherIndex,
+    Add1<Ur::Rank>: Unsigned + Add<B1>,
+    Add1<<<Ur as OtherIndex>::Output as Variance>::Rank>: Unsigned + Add<B1>,
+    T::Dimension: Pow<Add1<Ur::Rank>>,
+    T::Dimension: Pow<Add1<<<Ur as OtherIndex>::Output as Variance>::Rank>>,
+    Exp<T::Dimension, Add1<Ur::Rank>>: ArrayLength<f64>,
+    Exp<T::Dimension, Add1<<<Ur as OtherIndex>::Output as Variance>::Rank>>: ArrayLength<f64>,
+{
+    /// Calculates the Moore-Penrose pseudo-inverse of the matrix from its singular value
+    /// decomposition, as V Σ⁺ Uᵀ.
+    ///
+    /// Singular values not greater than `tol` times the largest one are treated as zero,
+    /// so unlike `inverse`, this always returns a result. For an invertible matrix (and a
+    /// small enough `tol`), it is the same as the inverse.
+    pub fn pseudo_inverse(
+        &self,
+        tol: f64,
+    ) -> Tensor<T, (<Ul as OtherIndex>::Output, <Ur as OtherIndex>::Output)> {
+        let n = T::dimension();
+        let (u, sigma, vt) = svd(self.coords_array(), n);
+        let max = sigma.iter().cloned().fold(0.0, f64::max);
+
+        let mut result = vec![0.0; n * n];
+        for (k, &s) in sigma.iter().enumerate() {
+            if s <= tol * max || s == 0.0 {
+                continue;
+            }
+            for i in 0..n {
+                for j in 0..n {
+                    result[i * n + j] += vt[k * n + i] * u[j * n + k] / s;
+                }
+            }
+        }
+        Tensor::from_slice(self.get_point().clone(), &result)
+    }
+}
+
+impl<T> Tensor<T, (ContravariantIndex, CovariantIndex)>
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U2>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+{
+    /// Calculates the exponential of the matrix.
+    ///
+    /// Symmetric matrices are handled through their eigendecomposition. For general
+    /// matrices, the scaling and squaring method with a Padé approximant is used, which
+    /// converges for any matrix, but loses accuracy when the norm of the matrix is very
+    /// large.
+    ///
+    /// Like the other matrix functions, this is only defined for (1, 1) tensors: the power
+    /// series contains products of the matrix with itself, which are tensors of the same
+    /// type only when one index is contravariant and the other covariant.
+    pub fn matrix_exp(&self) -> Matrix<T> {
+        let n = T::dimension();
+        let a = self.coords_array();
+        let result = if is_symmetric(a, n) {
+            let (values, vectors) = symmetric_eigen(a, n);
+            symmetric_function(&values, &vectors, n, f64::exp)
+        } else {
+            expm(a, n)
+        };
+        Tensor::from_slice(self.get_point().clone(), &result)
+    }
+
+    /// Calculates the principal logarithm of the matrix.
+    ///
+    /// The principal logarithm only exists if the matrix has no eigenvalues on the
+    /// closed negative real axis, which is assumed here. For symmetric matrices, this means
+    /// that the matrix must be positive definite, which is checked using its
+    /// eigendecomposition. General matrices are handled by taking repeated square roots
+    /// until the matrix is close to the identity, and then summing the Taylor series of
+    /// the logarithm - `None` is returned if the square roots fail to converge.
+    pub fn matrix_log(&self) -> Option<Matrix<T>> {
+        let n = T::dimension();
+        let a = self.coords_array();
+        let result = if is_symmetric(a, n) {
+            let (values, vectors) = symmetric_eigen(a, n);
+            if values.iter().any(|&x| x <= 0.0) {
+                return None;
+            }
+            symmetric_function(&values, &vectors, n, f64::ln)
+        } else {
+            logm(a, n)?
+        };
+        Some(Tensor::from_slice(self.get_point().clone(), &result))
+    }
+
+    /// Calculates the `n`-th power of the matrix using exponentiation by squaring.
+    ///
+    /// `n = 0` gives the identity. Negative powers are calculated as powers of the inverse,
+    /// so `None` is returned if `n < 0` and the matrix is singular. The inverse here is that
+    /// of the linear map, which is again a (1, 1) tensor - unlike `Tensor::inverse`, which
+    /// swaps the types of both indices, as needed for inverting a metric.
+    pub fn matrix_pow(&self, n: i32) -> Option<Matrix<T>> {
+        let dim = T::dimension();
+        let result = if n < 0 {
+            let inv = inverse(self.coords_array(), dim)?;
+            powm(&inv, n.unsigned_abs(), dim)
+        } else {
+            powm(self.coords_array(), n as u32, dim)
+        };
+        Some(Tensor::from_slice(self.get_point().clone(), &result))
+    }
+
+    /// Calculates the square root of a symmetric positive-definite matrix, i.e. the unique
+    /// symmetric positive-definite matrix whose square is `self`.
+    ///
+    /// The matrix must be symmetric and positive definite, which is checked using its
+    /// eigendecomposition - `None` is returned otherwise. A metric is a (0, 2) tensor, so its
+    /// square root (e.g. for whitening) has to be taken from its coordinates explicitly, as
+    /// in `Matrix::from_slice(p, g.coords_array()).matrix_sqrt()`.
+    pub fn matrix_sqrt(&self) -> Option<Matrix<T>> {
+        let n = T::dimension();
+        let a = self.coords_array();
+        if !is_symmetric(a, n) {
+            return None;
+        }
+        let (values, vectors) = symmetric_eigen(a, n);
+        if values.iter().any(|&x| x <= 0.0) {
+            return None;
+        }
+        let result = symmetric_function(&values, &vectors, n, f64::sqrt);
+        Some(Tensor::from_slice(self.get_point().clone(), &result))
+    }
+
+    /// Returns the matrix of a rotation by `angle` in the plane spanned by the axes
+    /// `axis_i` and `axis_j`, turning the i-th basis vector towards the j-th one.
+    ///
+    /// Panics if the axes are equal or out of range.
+    pub fn rotation(point: Point<T>, axis_i: usize, axis_j: usize, angle: f64) -> Matrix<T> {
+        let n = T::dimension();
+        assert!(axis_i < n && axis_j < n && axis_i != axis_j);
+        let mut result = Matrix::<T>::zero(point);
+        for k in 0..n {
+            result[k * n + k] = 1.0;
+        }
+        let (sin, cos) = angle.sin_cos();
+        result[axis_i * n + axis_i] = cos;
+        result[axis_j * n + axis_j] = cos;
+        result[axis_i * n + axis_j] = -sin;
+        result[axis_j * n + axis_i] = sin;
+        result
+    }
+
+    /// Returns the matrix of the reflection reversing the given axis.
+    ///
+    /// Panics if the axis is out of range.
+    pub fn reflection(point: Point<T>, axis: usize) -> Matrix<T> {
+        let n = T::dimension();
+        assert!(axis < n);
+        let mut result = Matrix::<T>::zero(point);
+        for k in 0..n {
+            result[k * n + k] = if k == axis { -1.0 } else { 1.0 };
+        }
+        result
+    }
+
+    /// Calculates the singular value decomposition A = U Σ Vᵀ of the matrix using the
+    /// one-sided Jacobi method. Returns the triple (U, σ, Vᵀ), where U and V are orthogonal
+    /// and σ contains the singular values (the diagonal of Σ) in decreasing order.
+    pub fn svd(&self) -> (Matrix<T>, GenericArray<f64, T::Dimension>, Matrix<T>) {
+        let (u, sigma, vt) = svd(self.coords_array(), T::dimension());
+        let point = self.get_point();
+        (
+            Matrix::<T>::from_slice(point.clone(), &u),
+            GenericArray::clone_from_slice(&sigma),
+            Matrix::<T>::from_slice(point.clone(), &vt),
+        )
+    }
+
+    /// Returns the numerical rank of the matrix, which is the number of its singular values
+    /// greater than `tol` times the largest singular value. The tolerance is thus relative,
+    /// which makes the result independent of the scale of the matrix; a value like 1e-12
+    /// is reasonable for matrices calculated with rounding errors. A zero matrix has rank 0.
+    pub fn numerical_rank(&self, tol: f64) -> usize {
+        let (_, sigma, _) = svd(self.coords_array(), T::dimension());
+        let max = sigma.iter().cloned().fold(0.0, f64::max);
+        sigma.iter().filter(|&&x| x > tol * max).count()
+    }
+
+    /// Applies the matrix to a vector, returning M^i_j v^j.
+    ///
+    /// Both tensors must be defined at the same point.
+    pub fn apply_to_vector(&self, v: &Vector<T>) -> Vector<T>
+    where
+        T::Dimension: Pow<U1>,
+        Exp<T::Dimension, U1>: ArrayLength<f64>,
+    {
+        assert!(self.get_point().approx_eq(v.get_point()));
+        let n = T::dimension();
+        let m = self.coords_array();
+        let mut result = Vector::<T>::zero(v.get_point().clone());
+        for i in 0..n {
+            result[i] = (0..n).map(|j| m[i * n + j] * v[j]).sum();
+        }
+        result
+    }
+
+    /// Estimates the spectral radius of the matrix (the largest magnitude of its
+    /// eigenvalues) using power iteration. Returns the magnitude together with the
+    /// associated eigenvector, normalized to unit Euclidean length of its coordinates.
+    ///
+    /// The iteration stops when the estimate changes by less than 1e-12 relatively, or
+    /// after `iterations` steps. In the latter case it hasn't necessarily converged (e.g.
+    /// when several eigenvalues have the same magnitude), and the last estimate is returned.
+    pub fn spectral_radius(&self, iterations: usize) -> (f64, Vector<T>)
+    where
+        T::Dimension: Pow<U1>,
+        Exp<T::Dimension, U1>: ArrayLength<f64>,
+    {
+        let n = T::dimension();
+        // a starting vector unlikely to be orthogonal to the dominant eigenvector
+        let mut v = Vector::<T>::zero(self.get_point().clone());
+        for i in 0..n {
+            v[i] = 1.0 + i as f64 / n as f64;
+        }
+        let norm = v.coords_array().iter().map(|x| x * x).sum::<f64>().sqrt();
+        v *= 1.0 / norm;
+
+        let mut radius = 0.0;
+        for _ in 0..iterations {
+            let w = self.apply_to_vector(&v);
+            let norm = w.coords_array().iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                return (0.0, v);
+            }
+            v = w * (1.0 / norm);
+            let converged = (norm - radius).abs() <= 1e-12 * norm;
+            radius = norm;
+            if converged {
+                break;
+            }
+        }
+        (radius, v)
+    }
+
+    /// Applies the affine map x ↦ Mx + t to the coordinates of a point, where M is the
+    /// matrix and t is the translation.
+    pub fn apply_to_point(
+        &self,
+        point: &Point<T>,
+        translation: &GenericArray<f64, T::Dimension>,
+    ) -> Point<T> {
+        let n = T::dimension();
+        let m = self.coords_array();
+        let mut result = Point::new(translation.clone());
+        for i in 0..n {
+            result[i] += (0..n).map(|j| m[i * n + j] * point[j]).sum::<f64>();
+        }
+        result
+    }
+
+    /// Calculates the polar decomposition A = QP of the matrix, where Q is orthogonal and
+    /// P is symmetric positive-definite. Returns the pair (Q, P).
+    ///
+    /// P is calculated as the square root of AᵀA, and Q as AP⁻¹. The matrix must be
+    /// invertible - `None` is returned otherwise.
+    pub fn polar_decomposition(&self) -> Option<(Matrix<T>, Matrix<T>)> {
+        let n = T::dimension();
+        let a = self.coords_array();
+        let ata = matmul(&transpose(a, n), a, n);
+
+        // AᵀA is singular if A is, up to rounding errors
+        let (values, vectors) = symmetric_eigen(&ata, n);
+        let max = values.iter().cloned().fold(0.0, f64::max);
+        if values.iter().any(|&x| x <= 1e-14 * max) {
+            return None;
+        }
+        let p = symmetric_function(&values, &vectors, n, f64::sqrt);
+        let p_inv = symmetric_function(&values, &vectors, n, |x| 1.0 / x.sqrt());
+        let q = matmul(a, &p_inv, n);
+
+        let point = self.get_point();
+        Some((
+            Matrix::<T>::from_slice(point.clone(), &q),
+            Matrix::<T>::from_slice(point.clone(), &p),
+        ))
+    }
+
+    /// Calculates the QR decomposition A = QR of the matrix using Householder reflections,
+    /// where Q is orthogonal and R is upper triangular, i.e. its coordinates with the first
+    /// index greater than the second one vanish. Returns the pair (Q, R).
+    pub fn qr_decomposition(&self) -> (Matrix<T>, Matrix<T>) {
+        let (q, r) = qr(self.coords_array(), T::dimension());
+        let point = self.get_point();
+        (
+            Matrix::<T>::from_slice(point.clone(), &q),
+            Matrix::<T>::from_slice(point.clone(), &r),
+        )
+    }
+
+    /// Solves the normal equations AᵀA x = Aᵀb for x, where A is `self`.
+    ///
+    /// For an invertible A, the solution is the same as that of A x = b, but it is found
+    /// using the QR decomposition of A (as R x = Qᵀb), which behaves better than the LU
+    /// decomposition when A is nearly singular. Returns `None` if A is singular.
+    pub fn solve_normal_equations(&self, b: &Vector<T>) -> Option<Vector<T>>
+    where
+        T::Dimension: Pow<U1>,
+        Exp<T::Dimension, U1>: ArrayLength<f64>,
+    {
+        let n = T::dimension();
+        let (q, r) = qr(self.coords_array(), n);
+        let scale = norm_inf(&r, n);
+
+        let mut x: Vec<f64> = (0..n)
+            .map(|i| (0..n).map(|k| q[k * n + i] * b.coords_array()[k]).sum())
+            .collect();
+        for i in (0..n).rev() {
+            if r[i * n + i].abs() <= 1e-14 * scale {
+                return None;
+            }
+            for j in i + 1..n {
+                x[i] -= r[i * n + j] * x[j];
+            }
+            x[i] /= r[i * n + i];
+        }
+
+        Some(Vector::<T>::from_slice(self.get_point().clone(), &x))
+    }
+
+    // Returns the pair of products (AB, BA)
+    fn products(&self, other: &Matrix<T>) -> (Matrix<T>, Matrix<T>) {
+        assert!(self.get_point().approx_eq(other.get_point()));
+        let n = T::dimension();
+        let (a, b) = (self.coords_array(), other.coords_array());
+        let point = self.get_point();
+        (
+            Matrix::<T>::from_slice(point.clone(), &matmul(a, b, n)),
+            Matrix::<T>::from_slice(point.clone(), &matmul(b, a, n)),
+        )
+    }
+
+    /// Returns the commutator [A, B] = AB - BA of the matrices.
+    ///
+    /// Both matrices must be defined at the same point.
+    pub fn commutator(&self, other: &Matrix<T>) -> Matrix<T> {
+        let (ab, ba) = self.products(other);
+        ab - ba
+    }
+
+    /// Returns the anticommutator {A, B} = AB + BA of the matrices, which is twice their
+    /// Jordan product. Unlike the commutator, it is symmetric in A and B, and {A, A} = 2A².
+    ///
+    /// Both matrices must be defined at the same point.
+    pub fn anticommutator(&self, other: &Matrix<T>) -> Matrix<T> {
+        let (ab, ba) = self.products(other);
+        ab + ba
+    }
+}
@@ -0,0 +1,122 @@
+//! LU decomposition of plain n x n matrices stored row by row in slices, in the same
+//! order as the coordinates of a rank 2 tensor.
+//!
+//! This is the single implementation behind `Tensor::inverse` and `Tensor::determinant`,
+//! as well as the matrix functions in `linalg`, so that they all treat singular matrices
+//! in the same way.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+// Pivots smaller than this (times the dimension), relative to the largest element in their
+// row, are treated as zero - the matrix is then singular up to rounding errors.
+const PIVOT_TOLERANCE: f64 = 8.0 * f64::EPSILON;
+
+// The LU decomposition with scaled partial pivoting, PA = LU
+pub(crate) struct Lu {
+    n: usize,
+    // L below the diagonal (with an implicit unit diagonal) and U on and above it
+    lu: Vec<f64>,
+    // the row of A moved to each row of PA
+    perm: Vec<usize>,
+    // whether P is an odd permutation
+    odd: bool,
+}
+
+impl Lu {
+    // Decomposes the matrix. Returns None if it is singular, or so close to singular that
+    // rounding errors could make up the difference.
+    pub(crate) fn new(a: &[f64], n: usize) -> Option<Self> {
+        let mut lu = a.to_vec();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut odd = false;
+
+        // the pivots are chosen relative to the largest element in their row
+        let mut scale = vec![0.0; n];
+        for (i, s) in scale.iter_mut().enumerate() {
+            *s = lu[i * n..(i + 1) * n]
+                .iter()
+                .map(|x| x.abs())
+                .fold(0.0, f64::max);
+            if *s == 0.0 {
+                return None;
+            }
+        }
+
+        let tol = PIVOT_TOLERANCE * n as f64;
+        for k in 0..n {
+            let relative = |i: usize| lu[i * n + k].abs() / scale[i];
+            let pivot = (k..n)
+                .max_by(|&i, &j| relative(i).total_cmp(&relative(j)))
+                .unwrap();
+            if relative(pivot) <= tol {
+                return None;
+            }
+            if pivot != k {
+                for j in 0..n {
+                    lu.swap(pivot * n + j, k * n + j);
+                }
+                perm.swap(pivot, k);
+                scale.swap(pivot, k);
+                odd = !odd;
+            }
+
+            for i in k + 1..n {
+                let factor = lu[i * n + k] / lu[k * n + k];
+                lu[i * n + k] = factor;
+                for j in k + 1..n {
+                    lu[i * n + j] -= factor * lu[k * n + j];
+                }
+            }
+        }
+
+        Some(Self { n, lu, perm, odd })
+    }
+
+    pub(crate) fn determinant(&self) -> f64 {
+        let n = self.n;
+        let product: f64 = (0..n).map(|i| self.lu[i * n + i]).product();
+        if self.odd {
+            -product
+        } else {
+            product
+        }
+    }
+
+    // Solves the system Ax = b
+    pub(crate) fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.n;
+        let mut x: Vec<f64> = self.perm.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.lu[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= self.lu[i * n + j] * x[j];
+            }
+            x[i] /= self.lu[i * n + i];
+        }
+        x
+    }
+
+    pub(crate) fn inverse(&self) -> Vec<f64> {
+        let n = self.n;
+        let mut result = vec![0.0; n * n];
+        let mut e = vec![0.0; n];
+        for col in 0..n {
+            e[col] = 1.0;
+            for (row, x) in self.solve(&e).into_iter().enumerate() {
+                result[row * n + col] = x;
+            }
+            e[col] = 0.0;
+        }
+        result
+    }
+}
+
+// Calculates the inverse of the matrix. Returns None if it is singular.
+pub(crate) fn inverse(a: &[f64], n: usize) -> Option<Vec<f64>> {
+    Lu::new(a, n).map(|lu| lu.inverse())
+}
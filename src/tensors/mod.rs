@@ -1,11 +1,40 @@
 //! Module containing definitions of tensors and operations on them.
+#[cfg(feature = "complex")]
+mod complex;
+mod density;
+mod einsum;
+mod error;
+mod free;
+pub(crate) mod kernels;
+#[cfg(feature = "std")]
+pub(crate) mod linalg;
+mod lu;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+mod serialize;
+mod symmetry;
 mod tensor;
 mod variance;
+mod view;
 
+pub use self::density::{Density, TensorDensity};
+pub use self::einsum::{
+    contraction_cost, einsum, einsum_single, path_cost, plan_contraction, ContractionPlan,
+    MAX_PLANNED_OPERANDS,
+};
+pub use self::error::TensorError;
+pub use self::free::FreeTensor;
+pub use self::symmetry::{IndexSymmetry, Symmetry};
 pub use self::tensor::{
-    Covector, InnerProduct, InvTwoForm, Matrix, Scalar, Tensor, TwoForm, Vector,
+    CoordIterator, Covector, InnerProduct, InvTwoForm, Matrix, MemoryOrder, Scalar, Tensor,
+    TensorStats, TwoForm, Vector,
 };
 pub use self::variance::{
     Concat, Contract, Contracted, ContravariantIndex, CovariantIndex, IndexType, Joined,
-    OtherIndex, TensorIndex, Variance,
+    LoweredIndex, OtherIndex, RaisedIndex, ReplaceIndex, Replaced, TensorIndex, Variance,
 };
+pub use self::view::TensorView;
+
+#[cfg(feature = "complex")]
+pub use self::complex::ComplexTensor;
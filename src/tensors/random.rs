@@ -0,0 +1,81 @@
+//! This module contains constructors of random tensors.
+
+use super::{Tensor, TensorIndex, Variance};
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::typenum::{consts::B1, uint::Unsigned, Add1, Exp, Pow};
+use generic_array::ArrayLength;
+use rand::distributions::Distribution;
+use rand::Rng;
+use std::ops::Add;
+
+impl<T, V> Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Creates a tensor at a given point with the coordinates sampled independently from
+    /// the distribution.
+    pub fn random<R, D>(point: Point<T>, rng: &mut R, distribution: &D) -> Self
+    where
+        R: Rng + ?Sized,
+        D: Distribution<f64>,
+    {
+        let mut result = Self::zero(point);
+        for i in 0..Self::get_num_coords() {
+            result[i] = distribution.sample(rng);
+        }
+        result
+    }
+}
+
+impl<T, Ul, Ur> Tensor<T, (Ul, Ur)>
+where
+    T: CoordinateSystem,
+    Ul: TensorIndex,
+    Ur: TensorIndex,
+    Add1<Ur::Rank>: Unsigned + Add<B1>,
+    T::Dimension: Pow<Add1<Ur::Rank>>,
+    Exp<T::Dimension, Add1<Ur::Rank>>: ArrayLength<f64>,
+{
+    /// Creates a symmetric matrix at a given point, with the coordinates on and above the
+    /// diagonal sampled independently from the distribution.
+    pub fn random_symmetric<R, D>(point: Point<T>, rng: &mut R, distribution: &D) -> Self
+    where
+        R: Rng + ?Sized,
+        D: Distribution<f64>,
+    {
+        let n = T::dimension();
+        let mut result = Self::zero(point);
+        for i in 0..n {
+            for j in i..n {
+                let value = distribution.sample(rng);
+                result[i * n + j] = value;
+                result[j * n + i] = value;
+            }
+        }
+        result
+    }
+
+    /// Creates a symmetric positive-definite matrix at a given point, as A·Aᵀ, where the
+    /// coordinates of A are sampled independently from the distribution.
+    ///
+    /// The result is only guaranteed to be positive semi-definite, but it is singular with
+    /// probability zero for continuous distributions.
+    pub fn random_spd<R, D>(point: Point<T>, rng: &mut R, distribution: &D) -> Self
+    where
+        R: Rng + ?Sized,
+        D: Distribution<f64>,
+    {
+        let n = T::dimension();
+        let a = Self::random(point.clone(), rng, distribution);
+        let mut result = Self::zero(point);
+        for i in 0..n {
+            for j in 0..n {
+                result[i * n + j] = (0..n).map(|k| a[i * n + k] * a[j * n + k]).sum();
+            }
+        }
+        result
+    }
+}
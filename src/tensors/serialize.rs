@@ -0,0 +1,81 @@
+//! This module implements serialization of tensors using serde.
+//!
+//! A tensor is serialized together with the name and the dimension of its coordinate system
+//! and its variance. When deserializing, the dimension and the variance are checked against
+//! the target type, so that e.g. a 3-dimensional tensor can't be loaded into a 4-dimensional
+//! system. The name of the system is informational only, as type names aren't guaranteed to
+//! be stable.
+
+use super::{IndexType, Tensor, Variance};
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::typenum::{Exp, Pow};
+use generic_array::ArrayLength;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize)]
+struct TensorData {
+    system: String,
+    dimension: usize,
+    variance: Vec<IndexType>,
+    point: Vec<f64>,
+    coords: Vec<f64>,
+}
+
+impl<T, V> Serialize for Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TensorData {
+            system: T::system_name().to_owned(),
+            dimension: T::dimension(),
+            variance: V::variance(),
+            point: self.get_point().coords_array().to_vec(),
+            coords: self.to_vec(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T, V> Deserialize<'de> for Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = TensorData::deserialize(deserializer)?;
+
+        if data.dimension != T::dimension() || data.point.len() != T::dimension() {
+            return Err(D::Error::custom(format!(
+                "dimension mismatch: expected {}, found {} (system {})",
+                T::dimension(),
+                data.dimension,
+                data.system
+            )));
+        }
+        if data.variance != V::variance() {
+            return Err(D::Error::custom(format!(
+                "variance mismatch: expected {:?}, found {:?}",
+                V::variance(),
+                data.variance
+            )));
+        }
+        if data.coords.len() != Self::get_num_coords() {
+            return Err(D::Error::invalid_length(
+                data.coords.len(),
+                &"the number of coordinates of the tensor",
+            ));
+        }
+
+        Ok(Tensor::from_slice(
+            Point::from_slice(&data.point),
+            &data.coords,
+        ))
+    }
+}
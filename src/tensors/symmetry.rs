@@ -0,0 +1,182 @@
+//! This module defines the `Symmetry` type, describing the symmetries of a tensor under
+//! exchanging its indices.
+
+use super::{Tensor, Variance};
+use crate::coordinates::CoordinateSystem;
+use crate::typenum::{Exp, Pow};
+use alloc::vec;
+use alloc::vec::Vec;
+use generic_array::ArrayLength;
+
+/// The symmetry of a tensor under exchanging the indices at two positions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IndexSymmetry {
+    /// The coordinates don't change when the indices are exchanged.
+    Symmetric(usize, usize),
+    /// The coordinates change their sign when the indices are exchanged.
+    Antisymmetric(usize, usize),
+}
+
+impl IndexSymmetry {
+    // Returns the positions of the indices and the sign picked up when exchanging them
+    fn parts(self) -> (usize, usize, f64) {
+        match self {
+            IndexSymmetry::Symmetric(i, j) => (i, j, 1.0),
+            IndexSymmetry::Antisymmetric(i, j) => (i, j, -1.0),
+        }
+    }
+}
+
+/// A description of the symmetries of a tensor, made of symmetries under exchanging pairs
+/// of indices - e.g. "symmetric in (0, 1) and antisymmetric in (2, 3)".
+///
+/// It can be used to check that a calculated tensor has the expected symmetries with
+/// `Tensor::has_symmetry`, or to remove the violations caused by rounding errors with
+/// `Tensor::enforce_symmetry`.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Symmetry {
+    pairs: Vec<IndexSymmetry>,
+}
+
+impl Symmetry {
+    /// Creates a description without any symmetries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the symmetry under exchanging the indices at positions `i` and `j`.
+    pub fn symmetric(mut self, i: usize, j: usize) -> Self {
+        self.pairs.push(IndexSymmetry::Symmetric(i, j));
+        self
+    }
+
+    /// Adds the antisymmetry under exchanging the indices at positions `i` and `j`.
+    pub fn antisymmetric(mut self, i: usize, j: usize) -> Self {
+        self.pairs.push(IndexSymmetry::Antisymmetric(i, j));
+        self
+    }
+
+    /// Returns the symmetries making up the description.
+    pub fn pairs(&self) -> &[IndexSymmetry] {
+        &self.pairs
+    }
+
+    // Returns all the permutations of the indices generated by the exchanges, each with the
+    // sign picked up by the coordinates, or `None` if some permutation can be reached with
+    // both signs (in which case only the zero tensor has the symmetries)
+    fn group(&self, rank: usize) -> Option<Vec<(Vec<usize>, f64)>> {
+        for pair in &self.pairs {
+            let (i, j, _) = pair.parts();
+            assert!(
+                i < rank && j < rank && i != j,
+                "invalid symmetry {:?}",
+                pair
+            );
+        }
+
+        let mut group = vec![((0..rank).collect::<Vec<_>>(), 1.0)];
+        let mut next = 0;
+        while next < group.len() {
+            let (perm, sign) = group[next].clone();
+            next += 1;
+            for pair in &self.pairs {
+                let (i, j, pair_sign) = pair.parts();
+                let mut new_perm = perm.clone();
+                new_perm.swap(i, j);
+                let new_sign = sign * pair_sign;
+                match group.iter().find(|(p, _)| *p == new_perm) {
+                    Some(&(_, s)) if s != new_sign => return None,
+                    Some(_) => {}
+                    None => group.push((new_perm, new_sign)),
+                }
+            }
+        }
+        Some(group)
+    }
+}
+
+impl<T, V> Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    V::Rank: ArrayLength<usize>,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Checks whether the tensor has the given symmetries, with every coordinate compared
+    /// to the corresponding one with exchanged indices up to the tolerance `tol`.
+    ///
+    /// Panics if a symmetry refers to a position not less than the rank, or exchanges an
+    /// index with itself.
+    pub fn has_symmetry(&self, symmetry: &Symmetry, tol: f64) -> bool {
+        let rank = V::rank();
+        let mut exchanged = vec![0; rank];
+        symmetry.pairs().iter().all(|pair| {
+            let (i, j, sign) = pair.parts();
+            assert!(
+                i < rank && j < rank && i != j,
+                "invalid symmetry {:?}",
+                pair
+            );
+            self.iter_coords().all(|coord| {
+                for (k, e) in exchanged.iter_mut().enumerate() {
+                    *e = coord[k];
+                }
+                exchanged.swap(i, j);
+                (self[&*coord] - sign * self[&exchanged[..]]).abs() <= tol
+            })
+        })
+    }
+
+    /// Returns the tensor with the given symmetries enforced, i.e. its projection onto the
+    /// tensors having them: the average of the tensor over all the permutations of the
+    /// indices generated by the exchanges, with the signs of the antisymmetries applied.
+    ///
+    /// The result has the symmetries exactly, not just up to rounding errors, and a tensor
+    /// which already has them (approximately) is changed only slightly. Panics under the
+    /// same conditions as `has_symmetry`.
+    pub fn enforce_symmetry(&self, symmetry: &Symmetry) -> Self {
+        let rank = V::rank();
+        let mut result = Self::zero(self.get_point().clone());
+        let group = match symmetry.group(rank) {
+            Some(group) => group,
+            None => return result,
+        };
+        if group.len() == 1 {
+            return self.clone();
+        }
+
+        let mut done = vec![false; Self::get_num_coords()];
+        let mut permuted = vec![0; rank];
+        for coord in self.iter_coords() {
+            let l = Self::get_coord(&coord);
+            if done[l] {
+                continue;
+            }
+
+            // the positions of the coordinates related to this one by the symmetries
+            let orbit: Vec<(usize, f64)> = group
+                .iter()
+                .map(|(perm, sign)| {
+                    for (k, &i) in perm.iter().enumerate() {
+                        permuted[k] = coord[i];
+                    }
+                    (Self::get_coord(&permuted), *sign)
+                })
+                .collect();
+
+            // a coordinate which has to equal minus itself vanishes
+            let value = if orbit.iter().any(|&(m, sign)| m == l && sign < 0.0) {
+                0.0
+            } else {
+                orbit.iter().map(|&(m, sign)| sign * self[m]).sum::<f64>() / group.len() as f64
+            };
+            for &(m, sign) in &orbit {
+                result[m] = sign * value;
+                done[m] = true;
+            }
+        }
+
+        result
+    }
+}
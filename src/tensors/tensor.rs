@@ -1,18 +1,25 @@
 //! This module defines the `Tensor` type and all sorts of operations on it.
 
+use super::kernels::{self, Powers};
+use super::lu::{self, Lu};
 use super::variance::{Concat, Contract, Contracted, Joined, OtherIndex};
+use super::TensorError;
 use super::{ContravariantIndex, CovariantIndex, IndexType, TensorIndex, Variance};
-use crate::coordinates::{ConversionTo, CoordinateSystem, Point};
+use crate::coordinates::{Axes, ConversionTo, CoordinateSystem, Point, SubSystem};
 use crate::typenum::{
-    consts::{B1, U2},
+    consts::{B1, U1, U2},
     uint::Unsigned,
     Add1, Exp, Pow, Same,
 };
-use generic_array::{ArrayLength, GenericArray};
-use std::ops::{
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{
     Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub,
     SubAssign,
 };
+use generic_array::sequence::GenericSequence;
+use generic_array::{ArrayLength, GenericArray};
 
 /// Struct representing a tensor.
 ///
@@ -60,15 +67,57 @@ where
 {
 }
 
+impl<T, U> fmt::Debug for Tensor<T, U>
+where
+    T: CoordinateSystem,
+    U: Variance,
+    T::Dimension: Pow<U::Rank>,
+    Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Tensor{:?} at {:?}", &self.x, &self.p)
+    }
+}
+
+/// Summary statistics of the coordinates of a tensor, as returned by `Tensor::stats`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TensorStats {
+    /// The smallest coordinate
+    pub min: f64,
+    /// The largest coordinate
+    pub max: f64,
+    /// The arithmetic mean of the coordinates
+    pub mean: f64,
+    /// The (population) standard deviation of the coordinates
+    pub std: f64,
+}
+
+/// The order in which the coordinates of a tensor are laid out in a flat array.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MemoryOrder {
+    /// The last index varies the fastest, as in the internal storage of `Tensor` (and C).
+    RowMajor,
+    /// The first index varies the fastest, as in Fortran and LAPACK.
+    ColumnMajor,
+}
+
 /// A struct for iterating over the coordinates of a tensor.
+///
+/// It yields the multi-indices of all the coordinates, in the same order as they are stored
+/// in the tensor: lexicographically, with the last index varying the fastest. For example,
+/// for a rank 2 tensor in two dimensions the order is [0, 0], [0, 1], [1, 0], [1, 1]. A
+/// scalar has a single, empty multi-index.
 pub struct CoordIterator<U>
 where
     U: Variance,
     U::Rank: ArrayLength<usize>,
 {
-    started: bool,
     dimension: usize,
-    cur_coord: GenericArray<usize, U::Rank>,
+    // the next multi-index to be returned from the front and from the back
+    front: GenericArray<usize, U::Rank>,
+    back: GenericArray<usize, U::Rank>,
+    // the number of multi-indices left
+    remaining: usize,
 }
 
 impl<U> CoordIterator<U>
@@ -76,11 +125,29 @@ where
     U: Variance,
     U::Rank: ArrayLength<usize>,
 {
+    /// Creates an iterator over the multi-indices of a tensor of variance `U` in a space
+    /// of the given dimension.
     pub fn new(dimension: usize) -> Self {
         Self {
-            started: false,
             dimension,
-            cur_coord: <_>::default(),
+            front: <_>::default(),
+            back: GenericArray::generate(|_| dimension.saturating_sub(1)),
+            remaining: dimension.pow(U::rank() as u32),
+        }
+    }
+}
+
+impl<U> Clone for CoordIterator<U>
+where
+    U: Variance,
+    U::Rank: ArrayLength<usize>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dimension: self.dimension,
+            front: self.front.clone(),
+            back: self.back.clone(),
+            remaining: self.remaining,
         }
     }
 }
@@ -93,30 +160,58 @@ where
     type Item = GenericArray<usize, U::Rank>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if !self.started {
-            self.started = true;
-            return Some(self.cur_coord.clone());
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let result = self.front.clone();
+
+        // the rank is used instead of `self.front.len()`, since dereferencing an empty
+        // `GenericArray` isn't sound
+        for i in (0..U::rank()).rev() {
+            self.front[i] += 1;
+            if self.front[i] < self.dimension {
+                break;
+            }
+            self.front[i] = 0;
         }
 
-        // handle scalars
-        if self.cur_coord.is_empty() {
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<U> ExactSizeIterator for CoordIterator<U>
+where
+    U: Variance,
+    U::Rank: ArrayLength<usize>,
+{
+}
+
+impl<U> DoubleEndedIterator for CoordIterator<U>
+where
+    U: Variance,
+    U::Rank: ArrayLength<usize>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
             return None;
         }
+        self.remaining -= 1;
+        let result = self.back.clone();
 
-        let mut i = self.cur_coord.len() - 1;
-        loop {
-            self.cur_coord[i] += 1;
-            if self.cur_coord[i] < self.dimension {
+        for i in (0..U::rank()).rev() {
+            if self.back[i] > 0 {
+                self.back[i] -= 1;
                 break;
             }
-            self.cur_coord[i] = 0;
-            if i == 0 {
-                return None;
-            }
-            i -= 1;
+            self.back[i] = self.dimension - 1;
         }
 
-        Some(self.cur_coord.clone())
+        Some(result)
     }
 }
 
@@ -150,11 +245,38 @@ where
     pub fn get_coord(i: &[usize]) -> usize {
         assert_eq!(i.len(), V::rank());
         let dim = T::dimension();
-        let index = i.into_iter().fold(0, |res, idx| {
+        i.iter().fold(0, |res, idx| {
             assert!(*idx < dim);
             res * dim + idx
-        });
-        index
+        })
+    }
+
+    /// Converts a set of possibly negative tensor indices into a single index for the
+    /// internal array, wrapping each of them modulo the dimension.
+    ///
+    /// This is useful for periodic coordinates, where e.g. the index -1 refers to the last
+    /// coordinate. The length of the slice has to be compatible with the rank of the tensor.
+    pub fn get_coord_wrapped(i: &[isize]) -> usize {
+        assert_eq!(i.len(), V::rank());
+        let dim = T::dimension();
+        i.iter().fold(0, |res, idx| {
+            res * dim + idx.rem_euclid(dim as isize) as usize
+        })
+    }
+
+    /// Returns the coordinate at the given indices, wrapping them modulo the dimension.
+    ///
+    /// See `get_coord_wrapped`.
+    pub fn get_wrapped(&self, i: &[isize]) -> f64 {
+        self.x[Self::get_coord_wrapped(i)]
+    }
+
+    /// Returns a mutable reference to the coordinate at the given indices, wrapping them
+    /// modulo the dimension.
+    ///
+    /// See `get_coord_wrapped`.
+    pub fn get_wrapped_mut(&mut self, i: &[isize]) -> &mut f64 {
+        &mut self.x[Self::get_coord_wrapped(i)]
     }
 
     /// Returns the variance of the tensor, that is, the list of the index types.
@@ -163,6 +285,16 @@ where
         V::variance()
     }
 
+    /// Returns the variance of the tensor as an array, without allocating.
+    ///
+    /// The contents are the same as those returned by `get_variance`.
+    pub fn variance_array() -> GenericArray<IndexType, V::Rank>
+    where
+        V::Rank: ArrayLength<IndexType>,
+    {
+        V::variance_array()
+    }
+
     /// Returns the rank of the tensor
     pub fn get_rank() -> usize {
         V::rank()
@@ -173,6 +305,17 @@ where
         <T::Dimension as Pow<V::Rank>>::Output::to_usize()
     }
 
+    /// Returns the shape of the tensor, that is, the number of values each index can take.
+    ///
+    /// Since all indices range over the dimension of the coordinate system, this is
+    /// [Dimension; Rank].
+    pub fn shape() -> GenericArray<usize, V::Rank>
+    where
+        V::Rank: ArrayLength<usize>,
+    {
+        GenericArray::generate(|_| T::dimension())
+    }
+
     /// Creates a new, zero tensor at a given point
     pub fn zero(point: Point<T>) -> Self {
         Self {
@@ -200,7 +343,7 @@ where
     /// Creates a tensor at a given point with the coordinates defined by the slice.
     ///
     /// The number of elements in the slice must be equal to the number of coordinates
-    /// of the tensor.
+    /// of the tensor, otherwise this panics (see `try_from_slice`).
     ///
     /// One-dimensional slice represents an n-dimensional tensor in such a way, that
     /// the last index is the one that is changing the most often, i.e. the sequence is
@@ -214,6 +357,253 @@ where
         }
     }
 
+    /// Creates a tensor at a given point with the coordinates passed in the slice, checking
+    /// that they are all finite.
+    ///
+    /// Unlike `from_slice`, this returns an error instead of panicking if the length of the
+    /// slice is wrong, and also if any of the coordinates is NaN or infinite - which usually
+    /// means that something went wrong earlier, e.g. at a coordinate singularity.
+    pub fn try_from_slice(point: Point<T>, slice: &[f64]) -> Result<Self, TensorError> {
+        let expected = Self::get_num_coords();
+        if slice.len() != expected {
+            return Err(TensorError::WrongLength {
+                expected,
+                found: slice.len(),
+            });
+        }
+        if let Some(index) = slice.iter().position(|x| !x.is_finite()) {
+            return Err(TensorError::NonFinite { index });
+        }
+        Ok(Self::from_slice(point, slice))
+    }
+
+    /// Creates a tensor at a given point with the coordinates defined by the vector.
+    ///
+    /// The number of elements in the vector must be equal to the number of coordinates
+    /// of the tensor, otherwise this panics - use `try_from_vec` if the length isn't known
+    /// to be right. The order of the coordinates is the same as in `from_slice`.
+    pub fn from_vec(point: Point<T>, coords: Vec<f64>) -> Self {
+        Self::from_slice(point, &coords)
+    }
+
+    /// Creates a tensor at a given point with the coordinates defined by the vector,
+    /// returning an error instead of panicking if the length is wrong or any of the
+    /// coordinates isn't finite, like `try_from_slice`.
+    pub fn try_from_vec(point: Point<T>, coords: Vec<f64>) -> Result<Self, TensorError> {
+        Self::try_from_slice(point, &coords)
+    }
+
+    /// Returns a copy of the tensor's coordinates as a vector, in the same order
+    /// as accepted by `from_vec`.
+    pub fn to_vec(&self) -> Vec<f64> {
+        self.x.to_vec()
+    }
+
+    /// Creates a tensor at a given point from coordinates laid out in the given order.
+    ///
+    /// With `MemoryOrder::RowMajor`, this is the same as `from_vec`.
+    pub fn from_vec_order(point: Point<T>, coords: Vec<f64>, order: MemoryOrder) -> Self {
+        let mut result = Self::from_vec(point, coords);
+        if order == MemoryOrder::ColumnMajor {
+            let coords = result.x.clone();
+            for (l, x) in result.x.iter_mut().enumerate() {
+                *x = coords[Self::column_major_position(l)];
+            }
+        }
+        result
+    }
+
+    /// Returns a copy of the tensor's coordinates as a vector laid out in the given order.
+    ///
+    /// With `MemoryOrder::RowMajor`, this is the same as `to_vec`.
+    pub fn to_vec_order(&self, order: MemoryOrder) -> Vec<f64> {
+        let mut result = self.to_vec();
+        if order == MemoryOrder::ColumnMajor {
+            for (l, x) in self.x.iter().enumerate() {
+                result[Self::column_major_position(l)] = *x;
+            }
+        }
+        result
+    }
+
+    // Returns the position of the coordinate at the (row-major) position `l` in the
+    // column-major order, which is that of the reversed multi-index
+    fn column_major_position(mut l: usize) -> usize {
+        let d = T::dimension();
+        let mut result = 0;
+        for _ in 0..V::rank() {
+            result = result * d + l % d;
+            l /= d;
+        }
+        result
+    }
+
+    /// Returns an array marking the coordinates satisfying the predicate `pred`, in the same
+    /// order as the coordinates themselves.
+    ///
+    /// This is mostly useful for debugging, e.g. to see which components of a result are
+    /// nonzero.
+    pub fn mask<F>(&self, pred: F) -> GenericArray<bool, Exp<T::Dimension, V::Rank>>
+    where
+        F: Fn(f64) -> bool,
+        Exp<T::Dimension, V::Rank>: ArrayLength<bool>,
+    {
+        GenericArray::generate(|i| pred(self.x[i]))
+    }
+
+    /// Returns the number of coordinates with an absolute value greater than `tol`.
+    pub fn count_nonzero(&self, tol: f64) -> usize {
+        self.x.iter().filter(|x| x.abs() > tol).count()
+    }
+
+    /// Returns whether the coordinates of the tensors differ by at most `tol` each.
+    ///
+    /// The points at which the tensors are defined are ignored entirely, so this compares
+    /// just the algebraic content of the tensors.
+    pub fn components_eq(&self, other: &Self, tol: f64) -> bool {
+        self.x
+            .iter()
+            .zip(other.x.iter())
+            .all(|(a, b)| (a - b).abs() <= tol)
+    }
+
+    /// Returns the Frobenius inner product of the tensors, which is the sum of the products
+    /// of their corresponding coordinates.
+    ///
+    /// This is the natural inner product on the space of coordinates, but it doesn't use
+    /// the metric, so it depends on the coordinate system. Both tensors must be defined at
+    /// the same point.
+    pub fn frobenius_inner(&self, other: &Self) -> f64 {
+        assert!(self.p.approx_eq(&other.p));
+        self.x.iter().zip(other.x.iter()).map(|(a, b)| a * b).sum()
+    }
+
+    /// Returns the Frobenius norm of the tensor, i.e. the square root of the sum of the
+    /// squares of its coordinates.
+    #[cfg(feature = "std")]
+    pub fn frobenius_norm(&self) -> f64 {
+        self.x.iter().map(|x| x * x).sum::<f64>().sqrt()
+    }
+
+    /// Evaluates a covariant tensor, as a multilinear form, on the given vectors - that is,
+    /// contracts the k-th index with the k-th vector.
+    ///
+    /// Panics if the number of vectors isn't equal to the rank, if the tensor has a
+    /// contravariant index, or if the vectors are defined at a different point.
+    pub fn apply(&self, vectors: &[&Vector<T>]) -> f64
+    where
+        T::Dimension: Pow<U1>,
+        Exp<T::Dimension, U1>: ArrayLength<f64>,
+    {
+        let rank = V::rank();
+        assert_eq!(vectors.len(), rank);
+        assert!((0..rank).all(|i| V::index_type_at(i) == IndexType::Covariant));
+        assert!(vectors.iter().all(|v| self.p.approx_eq(&v.p)));
+
+        let d = T::dimension();
+        let mut result = 0.0;
+        for (l, x) in self.x.iter().enumerate() {
+            let mut term = *x;
+            let mut rest = l;
+            for v in vectors.iter().rev() {
+                term *= v.x[rest % d];
+                rest /= d;
+            }
+            result += term;
+        }
+        result
+    }
+
+    /// Returns the minimum, maximum, mean and standard deviation of the coordinates, which
+    /// is handy for spotting blow-ups in computed tensors. NaN coordinates propagate into
+    /// the mean and the standard deviation, but are ignored by the minimum and maximum.
+    #[cfg(feature = "std")]
+    pub fn stats(&self) -> TensorStats {
+        let n = self.x.len() as f64;
+        let mean = self.x.iter().sum::<f64>() / n;
+        let variance = self.x.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+        TensorStats {
+            min: self.x.iter().cloned().fold(f64::INFINITY, f64::min),
+            max: self.x.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            mean,
+            std: variance.sqrt(),
+        }
+    }
+
+    /// Returns the coordinate at the indices described by pairs (label, position), meaning
+    /// that the index at the given position takes the value of the coordinate with the given
+    /// label, as returned by `CoordinateSystem::coordinate_labels`.
+    ///
+    /// For example, `component_by_labels(&[("t", 0), ("r", 1)])` returns the (t, r) component
+    /// of a rank 2 tensor. Returns `None` if a label is unknown, or if the positions don't
+    /// cover every index exactly once.
+    pub fn component_by_labels(&self, labels: &[(&str, usize)]) -> Option<f64> {
+        let rank = V::rank();
+        if labels.len() != rank {
+            return None;
+        }
+
+        let mut idx = vec![None; rank];
+        for &(label, position) in labels {
+            let coord = T::coordinate_labels().iter().position(|l| *l == label)?;
+            match idx.get_mut(position) {
+                Some(slot @ None) => *slot = Some(coord),
+                _ => return None,
+            }
+        }
+
+        let idx: Vec<usize> = idx.into_iter().collect::<Option<_>>()?;
+        Some(self[&idx[..]])
+    }
+
+    /// Creates the basis tensor with a 1 at the given indices and zeros elsewhere,
+    /// i.e. the tensor product of the corresponding basis vectors and covectors.
+    ///
+    /// The number of indices must be equal to the rank of the tensor and each of them
+    /// must be less than the dimension.
+    pub fn basis_tensor(point: Point<T>, idx: &[usize]) -> Self {
+        let mut result = Self::zero(point);
+        result[Self::get_coord(idx)] = 1.0;
+        result
+    }
+
+    /// Creates a tensor with the given values on the generalized diagonal and zeros elsewhere.
+    ///
+    /// The diagonal consists of the components with all indices equal, i.e.
+    /// T_{ii...i} = values[i] (whatever the types of the indices), so for rank 2 this is a
+    /// diagonal matrix and `diagonal(p, &[1.0; n])` is the same as `unit(p)`. The number of
+    /// values must be equal to the dimension and the rank must be at least 1.
+    pub fn diagonal(point: Point<T>, values: &[f64]) -> Self {
+        let rank = V::rank();
+        assert!(rank > 0);
+        assert_eq!(values.len(), T::dimension());
+
+        let mut result = Self::zero(point);
+        for (i, value) in values.iter().enumerate() {
+            result[Self::get_coord(&vec![i; rank])] = *value;
+        }
+        result
+    }
+
+    /// Returns a tensor with the same point and components, but a different variance of the
+    /// same rank.
+    ///
+    /// # Safety
+    ///
+    /// This doesn't cause undefined behaviour, but it bypasses the guarantees of the type
+    /// system: the components are not transformed in any way, so the result is only
+    /// meaningful if the caller has accounted for the change of variance otherwise (e.g. by
+    /// having applied the metric manually).
+    pub unsafe fn reinterpret_variance<W>(self) -> Tensor<T, W>
+    where
+        W: Variance<Rank = V::Rank>,
+    {
+        Tensor {
+            p: self.p,
+            x: self.x,
+        }
+    }
+
     /// Contracts two indices
     ///
     /// The indices must be of opposite types. This is checked at compile time.
@@ -233,8 +623,9 @@ where
 
         let mut result = Tensor::<T, Contracted<V, Ul, Uh>>::zero(self.p.clone());
         let num_coords_result = Tensor::<T, Contracted<V, Ul, Uh>>::get_num_coords();
-        let modh = dim.pow((rank - 1 - index2) as u32);
-        let modl = dim.pow((rank - 2 - index1) as u32);
+        let powers = Powers::new(dim, rank);
+        let modh = powers[rank - 1 - index2];
+        let modl = powers[rank - 2 - index1];
 
         for coord in 0..num_coords_result {
             let coord1 = coord / modl;
@@ -267,6 +658,84 @@ where
     pub fn iter_coords(&self) -> CoordIterator<U> {
         CoordIterator::new(T::dimension())
     }
+
+    /// Returns a parallel iterator over the coordinates of the tensor, yielding the same
+    /// multi-indices as `iter_coords`.
+    ///
+    /// The multi-indices can be used to compute the components in parallel, e.g. with
+    /// `tensor.par_iter_coords().map(|i| f(tensor[&*i]))`.
+    #[cfg(feature = "rayon")]
+    pub fn par_iter_coords(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = GenericArray<usize, U::Rank>> {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+        let dim = T::dimension();
+        let rank = U::rank();
+        (0..Self::get_num_coords()).into_par_iter().map(move |k| {
+            // the last index varies the fastest, as in the internal array
+            GenericArray::generate(|i| k / dim.pow((rank - 1 - i) as u32) % dim)
+        })
+    }
+
+    /// Symmetrizes the tensor over all of its indices, i.e. averages the coordinates
+    /// over all permutations of the indices.
+    ///
+    /// Note that this doesn't check the types of the indices - symmetrizing over indices
+    /// of different types is not a coordinate-independent operation.
+    pub fn symmetrize(&self) -> Tensor<T, U> {
+        let perms = permutations(U::rank());
+        let mut result = Tensor::<T, U>::zero(self.p.clone());
+        let mut permuted = vec![0; U::rank()];
+
+        for coord in self.iter_coords() {
+            let mut sum = 0.0;
+            for perm in &perms {
+                for (k, &i) in perm.iter().enumerate() {
+                    permuted[k] = coord[i];
+                }
+                sum += self[&permuted[..]];
+            }
+            result[&*coord] = sum / perms.len() as f64;
+        }
+
+        result
+    }
+
+    /// Checks whether two tensors are equal after symmetrizing both of them, with every
+    /// coordinate compared up to the tolerance `tol`.
+    ///
+    /// This is useful when comparing symmetric tensors calculated in different ways, but
+    /// keep in mind that all information about the antisymmetric parts is lost - two
+    /// tensors differing only by an antisymmetric part will compare equal.
+    pub fn equals_symmetrized(&self, other: &Tensor<T, U>, tol: f64) -> bool {
+        let lhs = self.symmetrize();
+        let rhs = other.symmetrize();
+        (0..Self::get_num_coords()).all(|i| (lhs[i] - rhs[i]).abs() <= tol)
+    }
+}
+
+// Returns the maximum absolute row sum of an n x n matrix stored row by row
+fn max_row_sum(coords: &[f64], n: usize) -> f64 {
+    coords
+        .chunks(n)
+        .map(|row| row.iter().map(|x| x.abs()).sum())
+        .fold(0.0, f64::max)
+}
+
+// Returns all permutations of the numbers 0..n
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    let mut result = vec![];
+    for perm in permutations(n - 1) {
+        for pos in 0..n {
+            let mut new_perm = perm.clone();
+            new_perm.insert(pos, n - 1);
+            result.push(new_perm);
+        }
+    }
+    result
 }
 
 impl<'a, T, U> Index<&'a [usize]> for Tensor<T, U>
@@ -295,7 +764,7 @@ where
     }
 }
 
-impl<'a, T, U> Index<usize> for Tensor<T, U>
+impl<T, U> Index<usize> for Tensor<T, U>
 where
     T: CoordinateSystem,
     U: Variance,
@@ -309,7 +778,7 @@ where
     }
 }
 
-impl<'a, T, U> IndexMut<usize> for Tensor<T, U>
+impl<T, U> IndexMut<usize> for Tensor<T, U>
 where
     T: CoordinateSystem,
     U: Variance,
@@ -341,6 +810,306 @@ pub type TwoForm<T> = Tensor<T, (CovariantIndex, CovariantIndex)>;
 /// A rank 2 doubly contravariant tensor
 pub type InvTwoForm<T> = Tensor<T, (ContravariantIndex, ContravariantIndex)>;
 
+impl<T> Vector<T>
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U1> + Pow<U2>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+{
+    /// Returns the scalar product of two vectors with respect to the given metric,
+    /// g_ij u^i v^j.
+    ///
+    /// All the tensors must be defined at the same point.
+    pub fn dot(&self, other: &Vector<T>, metric: &TwoForm<T>) -> f64 {
+        assert!(self.p.approx_eq(&other.p) && self.p.approx_eq(&metric.p));
+        let d = T::dimension();
+        let mut result = 0.0;
+        for i in 0..d {
+            for j in 0..d {
+                result += metric[i * d + j] * self[i] * other[j];
+            }
+        }
+        result
+    }
+
+    /// Returns the natural pairing of the vector with a covector, w_i v^i, which doesn't
+    /// need a metric.
+    ///
+    /// Both tensors must be defined at the same point.
+    pub fn pair(&self, covector: &Covector<T>) -> f64 {
+        assert!(self.p.approx_eq(&covector.p));
+        self.x
+            .iter()
+            .zip(covector.x.iter())
+            .map(|(v, w)| v * w)
+            .sum()
+    }
+
+    /// Returns the vector scaled to unit norm with respect to the given metric, i.e. such
+    /// that |g_ij v^i v^j| = 1. The sign of the square is preserved, so in Lorentzian
+    /// signatures a timelike vector stays timelike.
+    ///
+    /// Null vectors (including the zero vector) can't be normalized, so `None` is returned
+    /// for them. A vector counts as null if its square vanishes up to rounding errors
+    /// relative to the magnitudes of the terms g_ij v^i v^j; in particular, a vector close
+    /// to the light cone may be reported as null. The metric must be defined at the same
+    /// point as the vector.
+    #[cfg(feature = "std")]
+    pub fn normalized(&self, metric: &TwoForm<T>) -> Option<Vector<T>> {
+        let square = self.dot(self, metric);
+        if square == 0.0 || square.abs() <= 1e-12 * self.square_scale(metric) {
+            return None;
+        }
+        let mut result = self.clone();
+        result *= 1.0 / square.abs().sqrt();
+        Some(result)
+    }
+
+    /// Projects the vector orthogonally (with respect to `metric`) onto the span of the
+    /// vectors in `basis`, which don't have to be orthonormal. Returns the pair
+    /// (projection, residual), which sum up to the original vector.
+    ///
+    /// The basis is orthogonalized with the Gram-Schmidt process. Vectors that are (up to
+    /// rounding errors) linear combinations of the previous ones, as well as null vectors,
+    /// are skipped, so for a degenerate basis the result is the projection onto the span
+    /// of the remaining vectors.
+    pub fn project_onto(&self, basis: &[Vector<T>], metric: &TwoForm<T>) -> (Vector<T>, Vector<T>) {
+        let mut orthogonal: Vec<(Vector<T>, f64)> = Vec::with_capacity(basis.len());
+        for e in basis {
+            let mut u = e.clone();
+            u.subtract_projections(orthogonal.iter().map(|(o, square)| (o, *square)), metric);
+            let square = u.dot(&u, metric);
+            if square != 0.0 && square.abs() > 1e-12 * e.dot(e, metric).abs() {
+                orthogonal.push((u, square));
+            }
+        }
+
+        let mut projection = Vector::<T>::zero(self.p.clone());
+        for (o, o_square) in &orthogonal {
+            projection.add_scaled(o.dot(self, metric) / o_square, o);
+        }
+        let mut residual = self.clone();
+        residual.add_scaled(-1.0, &projection);
+        (projection, residual)
+    }
+
+    /// Orthonormalizes the frame in place with respect to the metric, using the
+    /// (modified) Gram-Schmidt process: every vector has its components along the previous
+    /// ones removed and is then scaled to unit norm, |g_ij e^i e^j| = 1.
+    ///
+    /// Returns `false` if a vector becomes null in the process (in particular if the
+    /// vectors are linearly dependent up to rounding errors, judged relative to the vector
+    /// before the projections were removed), in which case the frame is left orthonormalized
+    /// only up to the previous vector. All the vectors and the metric must be defined at
+    /// the same point.
+    #[cfg(feature = "std")]
+    pub fn orthonormalize(frame: &mut [Vector<T>], metric: &TwoForm<T>) -> bool {
+        for k in 0..frame.len() {
+            let (previous, rest) = frame.split_at_mut(k);
+            let u = &mut rest[0];
+            let scale = u.square_scale(metric);
+            u.subtract_projections(
+                previous.iter().map(|o| (o, o.dot(o, metric).signum())),
+                metric,
+            );
+            let square = u.dot(u, metric);
+            if square == 0.0 || square.abs() <= 1e-12 * scale {
+                return false;
+            }
+            *u *= 1.0 / square.abs().sqrt();
+        }
+        true
+    }
+
+    // Returns the sum of the magnitudes of the terms g_ij v^i v^j, against which the square
+    // of the vector is compared when deciding whether it is null
+    #[cfg(feature = "std")]
+    fn square_scale(&self, metric: &TwoForm<T>) -> f64 {
+        let d = T::dimension();
+        (0..d)
+            .flat_map(|i| (0..d).map(move |j| (i, j)))
+            .map(|(i, j)| (metric.x[i * d + j] * self.x[i] * self.x[j]).abs())
+            .sum()
+    }
+
+    // Removes from the vector its components along the given mutually orthogonal vectors,
+    // each of them paired with its square
+    fn subtract_projections<'a, I>(&mut self, orthogonal: I, metric: &TwoForm<T>)
+    where
+        I: Iterator<Item = (&'a Vector<T>, f64)>,
+        T: 'a,
+    {
+        for (o, square) in orthogonal {
+            let coefficient = o.dot(self, metric) / square;
+            self.add_scaled(-coefficient, o);
+        }
+    }
+}
+
+impl<T, V> Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    T::Dimension: Pow<V::Rank> + Pow<U2>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+{
+    /// Lowers the index at position `idx` using the given metric, g_ij T^..j.., without
+    /// the need for a `MetricSystem`.
+    ///
+    /// The variance `W` of the result must be that of `self` with the index at `idx`
+    /// made covariant (usually given explicitly, e.g. `lower_index_with::<(CovariantIndex,
+    /// CovariantIndex)>`), which is checked at runtime. An error is returned if the index
+    /// doesn't exist or isn't contravariant, if `W` doesn't match, or if the metric is
+    /// defined at another point.
+    pub fn lower_index_with<W>(
+        &self,
+        metric: &TwoForm<T>,
+        idx: usize,
+    ) -> Result<Tensor<T, W>, TensorError>
+    where
+        W: Variance,
+        T::Dimension: Pow<W::Rank>,
+        Exp<T::Dimension, W::Rank>: ArrayLength<f64>,
+    {
+        self.change_indices(
+            metric.get_point(),
+            metric.coords_array(),
+            &[idx],
+            IndexType::Contravariant,
+        )
+    }
+
+    /// Raises the index at position `idx` using the given inverse metric, g^ij T_..j..,
+    /// without the need for a `MetricSystem`.
+    ///
+    /// The variance `W` of the result must be that of `self` with the index at `idx`
+    /// made contravariant, which is checked at runtime. An error is returned if the index
+    /// doesn't exist or isn't covariant, if `W` doesn't match, or if the metric is defined
+    /// at another point.
+    pub fn raise_index_with<W>(
+        &self,
+        inv_metric: &InvTwoForm<T>,
+        idx: usize,
+    ) -> Result<Tensor<T, W>, TensorError>
+    where
+        W: Variance,
+        T::Dimension: Pow<W::Rank>,
+        Exp<T::Dimension, W::Rank>: ArrayLength<f64>,
+    {
+        self.change_indices(
+            inv_metric.get_point(),
+            inv_metric.coords_array(),
+            &[idx],
+            IndexType::Covariant,
+        )
+    }
+
+    /// Lowers the indices at all the given positions using the given metric, like repeated
+    /// calls to `lower_index_with`, but without intermediate tensors.
+    ///
+    /// The variance `W` of the result must be that of `self` with the listed indices made
+    /// covariant. An error is returned if a position is listed twice, if an index doesn't
+    /// exist or isn't contravariant, if `W` doesn't match, or if the metric is defined at
+    /// another point.
+    pub fn lower_indices<W>(
+        &self,
+        metric: &TwoForm<T>,
+        idx: &[usize],
+    ) -> Result<Tensor<T, W>, TensorError>
+    where
+        W: Variance,
+        T::Dimension: Pow<W::Rank>,
+        Exp<T::Dimension, W::Rank>: ArrayLength<f64>,
+    {
+        self.change_indices(
+            metric.get_point(),
+            metric.coords_array(),
+            idx,
+            IndexType::Contravariant,
+        )
+    }
+
+    /// Raises the indices at all the given positions using the given inverse metric, like
+    /// repeated calls to `raise_index_with`, but without intermediate tensors.
+    ///
+    /// The variance `W` of the result must be that of `self` with the listed indices made
+    /// contravariant. An error is returned if a position is listed twice, if an index
+    /// doesn't exist or isn't covariant, if `W` doesn't match, or if the metric is defined
+    /// at another point.
+    pub fn raise_indices<W>(
+        &self,
+        inv_metric: &InvTwoForm<T>,
+        idx: &[usize],
+    ) -> Result<Tensor<T, W>, TensorError>
+    where
+        W: Variance,
+        T::Dimension: Pow<W::Rank>,
+        Exp<T::Dimension, W::Rank>: ArrayLength<f64>,
+    {
+        self.change_indices(
+            inv_metric.get_point(),
+            inv_metric.coords_array(),
+            idx,
+            IndexType::Covariant,
+        )
+    }
+
+    // Contracts the indices at the positions in `idx`, which must be of the type `from`, with
+    // the first index of the symmetric rank 2 tensor `g`. InvalidIndex is returned for the
+    // first position at which `W` isn't the variance of the result.
+    fn change_indices<W>(
+        &self,
+        point: &Point<T>,
+        g: &[f64],
+        idx: &[usize],
+        from: IndexType,
+    ) -> Result<Tensor<T, W>, TensorError>
+    where
+        W: Variance,
+        T::Dimension: Pow<W::Rank>,
+        Exp<T::Dimension, W::Rank>: ArrayLength<f64>,
+    {
+        let rank = V::rank();
+        for (n, &i) in idx.iter().enumerate() {
+            if i >= rank || V::index_type_at(i) != from {
+                return Err(TensorError::InvalidIndex { index: i });
+            }
+            if idx[..n].contains(&i) {
+                return Err(TensorError::DuplicateIndex { index: i });
+            }
+        }
+        // the first position at which the variance of the result doesn't match
+        let mismatch = (0..rank.max(W::rank())).find(|&i| {
+            i >= rank
+                || i >= W::rank()
+                || (W::index_type_at(i) == V::index_type_at(i)) == idx.contains(&i)
+        });
+        if let Some(index) = mismatch {
+            return Err(TensorError::InvalidIndex { index });
+        }
+        self.check_point(point)?;
+
+        let d = T::dimension();
+        let mut result = Tensor::<T, W>::zero(self.p.clone());
+        result.x.copy_from_slice(&self.x);
+        let mut scratch = result.x.clone();
+        for &i in idx {
+            let stride = d.pow((rank - 1 - i) as u32);
+            for (l, r) in scratch.iter_mut().enumerate() {
+                let a = (l / stride) % d;
+                let base = l - a * stride;
+                *r = (0..d)
+                    .map(|j| g[a * d + j] * result.x[base + j * stride])
+                    .sum();
+            }
+            core::mem::swap(&mut result.x, &mut scratch);
+        }
+        Ok(result)
+    }
+}
+
 impl<T: CoordinateSystem> Deref for Scalar<T> {
     type Target = f64;
 
@@ -365,10 +1134,20 @@ where
     Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
 {
     fn add_assign(&mut self, rhs: Self) {
-        assert!(self.p == rhs.p);
-        for i in 0..(Self::get_num_coords()) {
-            self[i] += rhs[i];
-        }
+        *self += &rhs;
+    }
+}
+
+impl<'a, T, U> AddAssign<&'a Tensor<T, U>> for Tensor<T, U>
+where
+    T: CoordinateSystem,
+    U: Variance,
+    T::Dimension: Pow<U::Rank>,
+    Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
+{
+    fn add_assign(&mut self, rhs: &'a Tensor<T, U>) {
+        assert!(self.p.approx_eq(&rhs.p));
+        kernels::zip_apply(&mut self.x, &rhs.x, |a, b| *a += b);
     }
 }
 
@@ -395,10 +1174,20 @@ where
     Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
 {
     fn sub_assign(&mut self, rhs: Self) {
-        assert!(self.p == rhs.p);
-        for i in 0..(Self::get_num_coords()) {
-            self[i] -= rhs[i];
-        }
+        *self -= &rhs;
+    }
+}
+
+impl<'a, T, U> SubAssign<&'a Tensor<T, U>> for Tensor<T, U>
+where
+    T: CoordinateSystem,
+    U: Variance,
+    T::Dimension: Pow<U::Rank>,
+    Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
+{
+    fn sub_assign(&mut self, rhs: &'a Tensor<T, U>) {
+        assert!(self.p.approx_eq(&rhs.p));
+        kernels::zip_apply(&mut self.x, &rhs.x, |a, b| *a -= b);
     }
 }
 
@@ -425,9 +1214,7 @@ where
     Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
 {
     fn mul_assign(&mut self, rhs: f64) {
-        for i in 0..(Self::get_num_coords()) {
-            self[i] *= rhs;
-        }
+        kernels::apply(&mut self.x, |a| *a *= rhs);
     }
 }
 
@@ -469,9 +1256,7 @@ where
     Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
 {
     fn div_assign(&mut self, rhs: f64) {
-        for i in 0..(Self::get_num_coords()) {
-            self[i] /= rhs;
-        }
+        kernels::apply(&mut self.x, |a| *a /= rhs);
     }
 }
 
@@ -490,6 +1275,69 @@ where
     }
 }
 
+impl<T, U> Tensor<T, U>
+where
+    T: CoordinateSystem,
+    U: Variance,
+    T::Dimension: Pow<U::Rank>,
+    Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
+{
+    /// Adds `other` multiplied by `s` to `self` in a single pass, without creating
+    /// an intermediate tensor.
+    ///
+    /// Equivalent to `self += other.clone() * s`.
+    #[doc(alias = "axpy")]
+    pub fn add_scaled(&mut self, s: f64, other: &Self) {
+        assert!(self.p.approx_eq(&other.p));
+        kernels::zip_apply(&mut self.x, &other.x, |a, b| *a += s * b);
+    }
+
+    /// Multiplies `self` by `a` and adds `b` in a single pass, without creating
+    /// an intermediate tensor.
+    ///
+    /// Equivalent to `self = self * a + b.clone()`.
+    pub fn mul_add_assign(&mut self, a: f64, b: &Self) {
+        assert!(self.p.approx_eq(&b.p));
+        kernels::zip_apply(&mut self.x, &b.x, |x, y| *x = *x * a + y);
+    }
+
+    // Returns an error if the tensors aren't defined at the same point
+    fn check_point(&self, other: &Point<T>) -> Result<(), TensorError> {
+        if self.p.approx_eq(other) {
+            Ok(())
+        } else {
+            Err(TensorError::PointMismatch {
+                left: self.p.coords_array().to_vec(),
+                right: other.coords_array().to_vec(),
+            })
+        }
+    }
+
+    /// Adds two tensors, returning an error instead of panicking if they are defined at
+    /// different points.
+    pub fn try_add(mut self, rhs: Self) -> Result<Self, TensorError> {
+        self.check_point(&rhs.p)?;
+        kernels::zip_apply(&mut self.x, &rhs.x, |a, b| *a += b);
+        Ok(self)
+    }
+
+    /// Subtracts two tensors, returning an error instead of panicking if they are defined at
+    /// different points.
+    pub fn try_sub(mut self, rhs: Self) -> Result<Self, TensorError> {
+        self.check_point(&rhs.p)?;
+        kernels::zip_apply(&mut self.x, &rhs.x, |a, b| *a -= b);
+        Ok(self)
+    }
+
+    /// Adds `other` multiplied by `s` to `self` like `add_scaled`, returning an error instead
+    /// of panicking if the tensors are defined at different points.
+    pub fn try_add_scaled(&mut self, s: f64, other: &Self) -> Result<(), TensorError> {
+        self.check_point(&other.p)?;
+        kernels::zip_apply(&mut self.x, &other.x, |a, b| *a += s * b);
+        Ok(())
+    }
+}
+
 // Tensor multiplication
 
 // For some reason this triggers recursion overflow when tested - to be investigated
@@ -511,7 +1359,7 @@ where
     type Output = Tensor<T, Joined<U, V>>;
 
     fn mul(self, rhs: Tensor<T, V>) -> Self::Output {
-        assert!(self.p == rhs.p);
+        assert!(self.p.approx_eq(&rhs.p));
         let mut result = Tensor::zero(self.p.clone());
         let num_coords2 = Tensor::<T, V>::get_num_coords();
         let num_coords_result = Self::Output::get_num_coords();
@@ -524,6 +1372,39 @@ where
     }
 }
 
+impl<T, U> Tensor<T, U>
+where
+    T: CoordinateSystem,
+    U: Variance,
+    U::Rank: ArrayLength<usize>,
+    T::Dimension: Pow<U::Rank>,
+    Exp<T::Dimension, U::Rank>: ArrayLength<f64>,
+{
+    /// Calculates the symmetric tensor product of two tensors, that is, the outer
+    /// product symmetrized over all indices.
+    ///
+    /// The symmetrization is normalized, so for two vectors the result is
+    /// (v^i w^j + v^j w^i) / 2.
+    ///
+    /// Due to the same compiler issue that affects the `*` operator, the variance of
+    /// `other` usually has to be specified explicitly: `v.sym_product::<ContravariantIndex>(&w)`.
+    pub fn sym_product<V>(&self, other: &Tensor<T, V>) -> Tensor<T, Joined<U, V>>
+    where
+        V: Variance,
+        V::Rank: ArrayLength<usize>,
+        T::Dimension: Pow<V::Rank>,
+        Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+        U: Concat<V>,
+        Joined<U, V>: Variance,
+        <Joined<U, V> as Variance>::Rank: ArrayLength<usize>,
+        T::Dimension: Pow<<Joined<U, V> as Variance>::Rank>,
+        Exp<T::Dimension, <Joined<U, V> as Variance>::Rank>: ArrayLength<f64>,
+    {
+        let product = <Self as Mul<Tensor<T, V>>>::mul(self.clone(), other.clone());
+        product.symmetrize()
+    }
+}
+
 /// Trait representing the inner product of two tensors.
 ///
 /// The inner product is just a multiplication followed by a contraction.
@@ -555,7 +1436,7 @@ where
     type Output = Tensor<T, Contracted<Joined<U, V>, Ul, Uh>>;
 
     fn inner_product(self, rhs: Tensor<T, V>) -> Self::Output {
-        assert_eq!(self.p, rhs.p);
+        assert!(self.p.approx_eq(&rhs.p));
         let indexl = Ul::to_usize();
         let indexh = Uh::to_usize();
         let num_coords_result = Self::Output::get_num_coords();
@@ -564,21 +1445,22 @@ where
         let dim = T::Dimension::to_usize();
 
         let mut result = Self::Output::zero(self.p.clone());
+        let powers = Powers::new(dim, u_rank + v_rank);
         let (modl, modh, modv) = match (indexl < u_rank, indexh < u_rank) {
             (true, true) => (
-                dim.pow((u_rank - 2 - indexl) as u32),
-                dim.pow((u_rank - 1 - indexh) as u32),
-                dim.pow(v_rank as u32),
+                powers[u_rank - 2 - indexl],
+                powers[u_rank - 1 - indexh],
+                powers[v_rank],
             ),
             (true, false) => (
-                dim.pow((u_rank - 1 - indexl) as u32),
-                dim.pow((u_rank + v_rank - 1 - indexh) as u32),
-                dim.pow((v_rank - 1) as u32),
+                powers[u_rank - 1 - indexl],
+                powers[u_rank + v_rank - 1 - indexh],
+                powers[v_rank - 1],
             ),
             (false, false) => (
-                dim.pow((u_rank + v_rank - 2 - indexl) as u32),
-                dim.pow((u_rank + v_rank - 1 - indexh) as u32),
-                dim.pow(v_rank as u32),
+                powers[u_rank + v_rank - 2 - indexl],
+                powers[u_rank + v_rank - 1 - indexh],
+                powers[v_rank - 2],
             ),
             _ => unreachable!(),
         };
@@ -592,7 +1474,7 @@ where
             (
                 coords1part1 * modl * dim * dim + coords1part2 * modh * dim + coords1part3,
                 coords2,
-                modl + modh,
+                modl * dim + modh,
                 0,
             )
         };
@@ -607,7 +1489,7 @@ where
                 coords1,
                 coords2part1 * modl * dim * dim + coords2part2 * modh * dim + coords2part3,
                 0,
-                modl + modh,
+                modl * dim + modh,
             )
         };
 
@@ -649,6 +1531,12 @@ where
     }
 }
 
+// Operations on rank 2 tensors treated as matrices.
+//
+// Both indices of a tensor range over the dimension of its coordinate system, so every
+// rank 2 tensor is a square matrix by construction - a non-square `Tensor` can't even be
+// expressed, and the type system rejects any attempt to use these functions on tensors
+// of other ranks.
 impl<T, Ul, Ur> Tensor<T, (Ul, Ur)>
 where
     T: CoordinateSystem,
@@ -679,6 +1567,72 @@ where
         result
     }
 
+    /// Returns the trace of the matrix (the contraction of its two indices) as a number,
+    /// instead of the `Scalar` returned by `trace`.
+    ///
+    /// Like with `trace`, the indices must be of opposite types.
+    pub fn trace_scalar(&self) -> f64
+    where
+        Ur: Same<<Ul as OtherIndex>::Output>,
+    {
+        let d = T::dimension();
+        (0..d).map(|i| self.x[i * d + i]).sum()
+    }
+
+    /// Returns the trace of the matrix product AB, where A is `self`, calculated directly as
+    /// Σ_ij A_ij B_ji without forming the product.
+    ///
+    /// The indices of B must be of the types opposite to the ones of A taken in reverse
+    /// order, so that both the product and its trace are contractions of opposite indices.
+    /// Both matrices must be defined at the same point.
+    pub fn trace_of_product(
+        &self,
+        other: &Tensor<T, (<Ur as OtherIndex>::Output, <Ul as OtherIndex>::Output)>,
+    ) -> f64 {
+        assert!(self.p.approx_eq(&other.p));
+        let d = T::dimension();
+        (0..d)
+            .flat_map(|i| (0..d).map(move |j| (i, j)))
+            .map(|(i, j)| self.x[i * d + j] * other.x[j * d + i])
+            .sum()
+    }
+
+    /// Returns the diagonal entries M[i][i] of the matrix as a vector.
+    ///
+    /// The entries are returned as a contravariant `Vector` whatever the types of the
+    /// indices, since the diagonal isn't a tensorial quantity: it depends on the coordinate
+    /// system and doesn't transform like either a vector or a covector. (The name `diagonal`
+    /// is already used for the constructor of diagonal tensors.)
+    pub fn diag(&self) -> Vector<T>
+    where
+        T::Dimension: Pow<U1>,
+        Exp<T::Dimension, U1>: ArrayLength<f64>,
+    {
+        let d = T::dimension();
+        let mut result = Vector::<T>::zero(self.p.clone());
+        for i in 0..d {
+            result[i] = self.x[i * d + i];
+        }
+        result
+    }
+
+    /// Writes the coordinates of the vector into the diagonal M[i][i] of the matrix, leaving
+    /// the other entries unchanged. This is the counterpart of `diag`.
+    ///
+    /// The vector must be defined at the same point as the matrix.
+    pub fn set_diagonal(&mut self, v: &Vector<T>)
+    where
+        T::Dimension: Pow<U1>,
+        Exp<T::Dimension, U1>: ArrayLength<f64>,
+    {
+        assert!(self.p.approx_eq(&v.p));
+        let d = T::dimension();
+        assert_eq!(v.x.len(), d);
+        for i in 0..d {
+            self.x[i * d + i] = v.x[i];
+        }
+    }
+
     /// Transposes the matrix
     pub fn transpose(&self) -> Tensor<T, (Ur, Ul)> {
         let mut result = Tensor::<T, (Ur, Ul)>::zero(self.p.clone());
@@ -691,158 +1645,50 @@ where
         result
     }
 
-    // Function calculating the LU decomposition of a matrix - found in the internet
-    // The decomposition is done in-place and a permutation vector is returned (or None
-    // if the matrix was singular)
-    fn lu_decompose(&mut self) -> Option<GenericArray<usize, T::Dimension>> {
-        let n = T::dimension();
-        let absmin = 1.0e-30_f64;
-        let mut result = GenericArray::default();
-        let mut row_norm = GenericArray::<f64, T::Dimension>::default();
-
-        let mut max_row = 0;
-
-        for i in 0..n {
-            let mut absmax = 0.0;
-
-            for j in 0..n {
-                let coord: &[usize] = &[i, j];
-                let maxtemp = self[coord].abs();
-                absmax = if maxtemp > absmax { maxtemp } else { absmax };
-            }
-
-            if absmax == 0.0 {
-                return None;
-            }
-
-            row_norm[i] = 1.0 / absmax;
-        }
-
-        for j in 0..n {
-            for i in 0..j {
-                for k in 0..i {
-                    let coord1: &[usize] = &[i, j];
-                    let coord2: &[usize] = &[i, k];
-                    let coord3: &[usize] = &[k, j];
-
-                    self[coord1] -= self[coord2] * self[coord3];
-                }
-            }
-
-            let mut absmax = 0.0;
-
-            for i in j..n {
-                let coord1: &[usize] = &[i, j];
-
-                for k in 0..j {
-                    let coord2: &[usize] = &[i, k];
-                    let coord3: &[usize] = &[k, j];
-
-                    self[coord1] -= self[coord2] * self[coord3];
-                }
-
-                let maxtemp = self[coord1].abs() * row_norm[i];
-
-                if maxtemp > absmax {
-                    absmax = maxtemp;
-                    max_row = i;
-                }
-            }
-
-            if max_row != j {
-                if (j == n - 2) && self[&[j, j + 1] as &[usize]] == 0.0 {
-                    max_row = j;
-                } else {
-                    for k in 0..n {
-                        let jk: &[usize] = &[j, k];
-                        let maxrow_k: &[usize] = &[max_row, k];
-                        let maxtemp = self[jk];
-                        self[jk] = self[maxrow_k];
-                        self[maxrow_k] = maxtemp;
-                    }
-
-                    row_norm[max_row] = row_norm[j];
-                }
-            }
-
-            result[j] = max_row;
-
-            let jj: &[usize] = &[j, j];
-
-            if self[jj] == 0.0 {
-                self[jj] = absmin;
-            }
-
-            if j != n - 1 {
-                let maxtemp = 1.0 / self[jj];
-                for i in j + 1..n {
-                    self[&[i, j] as &[usize]] *= maxtemp;
-                }
-            }
-        }
-
-        Some(result)
+    /// Function calculating the determinant of `self` using the LU decomposition.
+    ///
+    /// Like `inverse`, this is only defined for square matrices, which all rank 2 tensors are:
+    /// both indices range over `T::Dimension`, so squareness is enforced by the types.
+    /// Matrices which are singular up to rounding errors have a determinant of exactly 0.
+    pub fn determinant(&self) -> f64 {
+        Lu::new(self.coords_array(), T::dimension()).map_or(0.0, |lu| lu.determinant())
     }
 
-    // Function solving a linear system of equations (self*x = b) using the LU decomposition
-    fn lu_substitution(
-        &self,
-        b: &GenericArray<f64, T::Dimension>,
-        permute: &GenericArray<usize, T::Dimension>,
-    ) -> GenericArray<f64, T::Dimension> {
-        let mut result = b.clone();
+    /// Estimates the condition number of the matrix, κ = ‖A‖·‖A⁻¹‖, using the maximum
+    /// absolute row sum norm.
+    ///
+    /// Large values mean that the matrix is close to singular and that its inverse
+    /// (and anything calculated from it) may be inaccurate. Returns `f64::INFINITY`
+    /// for singular matrices, including those which are singular up to rounding errors.
+    pub fn condition_number(&self) -> f64 {
         let n = T::dimension();
-
-        for i in 0..n {
-            let mut tmp = result[permute[i]];
-            result[permute[i]] = result[i];
-            for j in (0..i).rev() {
-                tmp -= self[&[i, j] as &[usize]] * result[j];
-            }
-            result[i] = tmp;
-        }
-
-        for i in (0..n).rev() {
-            for j in i + 1..n {
-                result[i] -= self[&[i, j] as &[usize]] * result[j];
+        match self.inverse() {
+            Some(inv) => {
+                let result =
+                    max_row_sum(self.coords_array(), n) * max_row_sum(inv.coords_array(), n);
+                if result.is_finite() {
+                    result
+                } else {
+                    f64::INFINITY
+                }
             }
-            result[i] /= self[&[i, i] as &[usize]];
+            None => f64::INFINITY,
         }
-
-        result
     }
 
     /// Function calculating the inverse of `self` using the LU ddecomposition.
     ///
+    /// Like `determinant`, this is only defined for square matrices, which all rank 2
+    /// tensors are.
+    ///
     /// The return value is an `Option`, since `self` may be non-invertible -
     /// in such a case, None is returned
+    #[allow(clippy::type_complexity)]
     pub fn inverse(
         &self,
     ) -> Option<Tensor<T, (<Ul as OtherIndex>::Output, <Ur as OtherIndex>::Output)>> {
-        let mut result =
-            Tensor::<T, (<Ul as OtherIndex>::Output, <Ur as OtherIndex>::Output)>::zero(
-                self.p.clone(),
-            );
-
-        let mut tmp = self.clone();
-
-        let permute = match tmp.lu_decompose() {
-            Some(p) => p,
-            None => return None,
-        };
-
-        for i in 0..T::dimension() {
-            let mut dxm = GenericArray::<f64, T::Dimension>::default();
-            dxm[i] = 1.0;
-
-            let x = tmp.lu_substitution(&dxm, &permute);
-
-            for k in 0..T::dimension() {
-                result[&[k, i] as &[usize]] = x[k];
-            }
-        }
-
-        Some(result)
+        let inverse = lu::inverse(self.coords_array(), T::dimension())?;
+        Some(Tensor::from_slice(self.p.clone(), &inverse))
     }
 }
 
@@ -856,32 +1702,138 @@ where
 {
     pub fn convert<T2>(&self) -> Tensor<T2, U>
     where
+        U::Rank: ArrayLength<IndexType>,
         T2: CoordinateSystem + 'static,
         T2::Dimension: Pow<U::Rank> + Pow<U2> + Same<T::Dimension>,
         Exp<T2::Dimension, U::Rank>: ArrayLength<f64>,
         Exp<T2::Dimension, U2>: ArrayLength<f64>,
         T: ConversionTo<T2>,
     {
-        let mut result = Tensor::<T2, U>::zero(<T as ConversionTo<T2>>::convert_point(&self.p));
+        self.convert_with(
+            &<T as ConversionTo<T2>>::jacobian(&self.p),
+            &<T as ConversionTo<T2>>::inv_jacobian(&self.p),
+        )
+    }
+
+    /// Converts the tensor to the system `T2` like `convert`, but returns an error if the
+    /// point is a coordinate singularity instead of producing NaNs or garbage values.
+    ///
+    /// The point is considered singular if the condition number of the Jacobian there is
+    /// larger than 1e12 (e.g. at the poles of spherical coordinates, where the Jacobian isn't
+    /// invertible), or if any of the calculated values isn't finite.
+    pub fn try_convert<T2>(&self) -> Result<Tensor<T2, U>, TensorError>
+    where
+        U::Rank: ArrayLength<IndexType>,
+        T2: CoordinateSystem + 'static,
+        T2::Dimension: Pow<U::Rank> + Pow<U2> + Same<T::Dimension>,
+        Exp<T2::Dimension, U::Rank>: ArrayLength<f64>,
+        Exp<T2::Dimension, U2>: ArrayLength<f64>,
+        T: ConversionTo<T2>,
+    {
+        let singularity = || TensorError::CoordinateSingularity {
+            point: self.p.coords_array().to_vec(),
+        };
 
         let jacobian = <T as ConversionTo<T2>>::jacobian(&self.p);
+        if jacobian.condition_number() > 1e12 {
+            return Err(singularity());
+        }
+
         let inv_jacobian = <T as ConversionTo<T2>>::inv_jacobian(&self.p);
-        let variance = <U as Variance>::variance();
+        let result = self.convert_with(&jacobian, &inv_jacobian);
+        if result.coords_array().iter().all(|x| x.is_finite())
+            && result.p.coords_array().iter().all(|x| x.is_finite())
+        {
+            Ok(result)
+        } else {
+            Err(singularity())
+        }
+    }
 
-        for i in result.iter_coords() {
+    /// Pushes the tensor forward along the conversion to the system `T2`, i.e. contracts
+    /// each of its indices with the Jacobian J^a_i = ∂x'^a/∂x^i returned by
+    /// `ConversionTo::jacobian`. All the indices must be contravariant.
+    ///
+    /// For such tensors the result is the same as that of `convert`, but the inverse of the
+    /// Jacobian is never needed, so this also works where the conversion is singular (e.g.
+    /// at the origin of polar coordinates), and it states the intent more clearly.
+    pub fn pushforward<T2>(&self) -> Tensor<T2, U>
+    where
+        U::Rank: ArrayLength<IndexType>,
+        T2: CoordinateSystem + 'static,
+        T2::Dimension: Pow<U::Rank> + Pow<U2> + Same<T::Dimension>,
+        Exp<T2::Dimension, U::Rank>: ArrayLength<f64>,
+        Exp<T2::Dimension, U2>: ArrayLength<f64>,
+        T: ConversionTo<T2>,
+    {
+        assert!((0..U::rank()).all(|k| U::index_type_at(k) == IndexType::Contravariant));
+        let jacobian = <T as ConversionTo<T2>>::jacobian(&self.p);
+        // the inverse is only used for covariant indices, so any placeholder will do
+        let unused = Tensor::zero(jacobian.p.clone());
+        self.convert_with(&jacobian, &unused)
+    }
+
+    // Transforms the tensor using the given Jacobian and its inverse
+    fn convert_with<T2>(
+        &self,
+        jacobian: &Matrix<T2>,
+        inv_jacobian: &Tensor<T2, (CovariantIndex, ContravariantIndex)>,
+    ) -> Tensor<T2, U>
+    where
+        U::Rank: ArrayLength<IndexType>,
+        T2: CoordinateSystem + 'static,
+        T2::Dimension: Pow<U::Rank> + Pow<U2> + Same<T::Dimension>,
+        Exp<T2::Dimension, U::Rank>: ArrayLength<f64>,
+        Exp<T2::Dimension, U2>: ArrayLength<f64>,
+        T: ConversionTo<T2>,
+    {
+        let mut result = Tensor::<T2, U>::zero(<T as ConversionTo<T2>>::convert_point(&self.p));
+
+        // the multi-indices are only accessed element by element, since empty arrays can't
+        // be dereferenced and scalars have empty multi-indices
+        for (li, i) in result.iter_coords().enumerate() {
             let mut temp = 0.0;
-            for j in self.iter_coords() {
-                let mut temp2 = self[&*j];
-                for (k, v) in variance.iter().enumerate() {
-                    let coords = [i[k], j[k]];
-                    temp2 *= match *v {
-                        IndexType::Covariant => inv_jacobian[&coords[..]],
-                        IndexType::Contravariant => jacobian[&coords[..]],
+            for (lj, j) in self.iter_coords().enumerate() {
+                let mut temp2 = self.x[lj];
+                for k in 0..U::rank() {
+                    // J^i_j for upper indices, (J⁻¹)^j_i for lower ones
+                    temp2 *= match U::index_type_at(k) {
+                        IndexType::Covariant => inv_jacobian[&[j[k], i[k]][..]],
+                        IndexType::Contravariant => jacobian[&[i[k], j[k]][..]],
                     };
                 }
                 temp += temp2;
             }
-            result[&*i] = temp;
+            result.x[li] = temp;
+        }
+
+        result
+    }
+
+    /// Projects the tensor into the subsystem made of the axes `A`, keeping only the
+    /// components along these axes.
+    ///
+    /// For example, projecting the metric of a spacetime onto its spatial axes yields the
+    /// spatial metric on a slice of constant time.
+    pub fn project<A: Axes>(&self) -> Tensor<SubSystem<T, A>, U>
+    where
+        A::Dimension: Pow<U::Rank>,
+        Exp<A::Dimension, U::Rank>: ArrayLength<f64>,
+    {
+        let axes = A::axes();
+        let dim = T::dimension();
+        let sub_dim = axes.len();
+        let mut result = Tensor::<SubSystem<T, A>, U>::zero(self.p.project::<A>());
+
+        for k in 0..Tensor::<SubSystem<T, A>, U>::get_num_coords() {
+            // map the digits of the index in base sub_dim to the axes of the parent system
+            let (mut rest, mut coord, mut place) = (k, 0, 1);
+            for _ in 0..U::rank() {
+                coord += axes[rest % sub_dim] * place;
+                rest /= sub_dim;
+                place *= dim;
+            }
+            result[k] = self[coord];
         }
 
         result
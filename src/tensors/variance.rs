@@ -5,12 +5,17 @@ use crate::typenum::consts::{B1, U0, U1};
 use crate::typenum::uint::{UInt, Unsigned};
 use crate::typenum::{Add1, Sub1};
 use crate::typenum::{Cmp, Greater, Same};
-use std::ops::{Add, Sub};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Sub};
+use generic_array::sequence::GenericSequence;
+use generic_array::{ArrayLength, GenericArray};
 
 /// This enum serves to represent the type of a tensor. A tensor can have any number of indices,
 /// and each one can be either covariant (a lower index), or contravariant (an upper index).
 /// For example, a vector is a tensor with only one contravariant index.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IndexType {
     Covariant,
     Contravariant,
@@ -24,6 +29,22 @@ pub trait Variance {
         Self::Rank::to_usize()
     }
     fn variance() -> Vec<IndexType>;
+
+    /// Returns the type of the index at the given position, without allocating
+    /// the whole list of index types.
+    fn index_type_at(i: usize) -> IndexType {
+        Self::variance()[i]
+    }
+
+    /// Returns the list of index types as an array, without allocating.
+    ///
+    /// The contents are the same as those returned by `variance`.
+    fn variance_array() -> GenericArray<IndexType, Self::Rank>
+    where
+        Self::Rank: ArrayLength<IndexType>,
+    {
+        GenericArray::generate(Self::index_type_at)
+    }
 }
 
 impl Variance for () {
@@ -32,6 +53,10 @@ impl Variance for () {
     fn variance() -> Vec<IndexType> {
         vec![]
     }
+
+    fn index_type_at(i: usize) -> IndexType {
+        panic!("index {} out of range for a rank 0 variance", i)
+    }
 }
 
 /// Trait identifying a type as representing a tensor index. It is implemented
@@ -61,6 +86,10 @@ impl Variance for ContravariantIndex {
     fn variance() -> Vec<IndexType> {
         vec![IndexType::Contravariant]
     }
+    fn index_type_at(i: usize) -> IndexType {
+        assert_eq!(i, 0);
+        IndexType::Contravariant
+    }
 }
 
 impl Variance for CovariantIndex {
@@ -68,6 +97,10 @@ impl Variance for CovariantIndex {
     fn variance() -> Vec<IndexType> {
         vec![IndexType::Covariant]
     }
+    fn index_type_at(i: usize) -> IndexType {
+        assert_eq!(i, 0);
+        IndexType::Covariant
+    }
 }
 
 /// Trait representing the other index type
@@ -100,6 +133,14 @@ where
         result.append(&mut U::variance());
         result
     }
+
+    fn index_type_at(i: usize) -> IndexType {
+        if i == 0 {
+            T::index_type()
+        } else {
+            U::index_type_at(i - 1)
+        }
+    }
 }
 
 /// Operator trait used for concatenating two variances.
@@ -243,6 +284,90 @@ where
     type Output = (U, Removed<V, Sub1<UInt<T, B>>>);
 }
 
+/// An operator trait, replacing the indicated index of a variance with the index `W`
+pub trait ReplaceIndex<T: Unsigned, W: TensorIndex>: Variance {
+    type Output: Variance;
+}
+
+/// Helper type for index replacement
+pub type Replaced<T, U, W> = <T as ReplaceIndex<U, W>>::Output;
+
+/// The variance `V` with the index at position `I` made contravariant, e.g. the variance of
+/// the result of raising that index with the inverse metric.
+///
+/// ```
+/// # extern crate diffgeom;
+/// # extern crate generic_array;
+/// use diffgeom::coordinates::{CoordinateSystem, Point};
+/// use diffgeom::tensors::{
+///     ContravariantIndex, CovariantIndex, LoweredIndex, RaisedIndex, Tensor, TwoForm,
+/// };
+/// use generic_array::arr;
+/// use generic_array::typenum::consts::{U0, U1, U2};
+///
+/// struct Plane;
+/// impl CoordinateSystem for Plane {
+///     type Dimension = U2;
+/// }
+///
+/// type Mixed = (ContravariantIndex, CovariantIndex);
+///
+/// // instead of spelling out (CovariantIndex, CovariantIndex)
+/// fn lower_first(
+///     t: &Tensor<Plane, Mixed>,
+///     metric: &TwoForm<Plane>,
+/// ) -> Tensor<Plane, LoweredIndex<Mixed, U0>> {
+///     t.lower_index_with::<LoweredIndex<Mixed, U0>>(metric, 0).unwrap()
+/// }
+///
+/// let p = Point::new(arr![f64; 0.0, 0.0]);
+/// let t = Tensor::<Plane, Mixed>::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+/// let metric = TwoForm::new(p, arr![f64; 2.0, 0.0, 0.0, 1.0]);
+/// let lowered = lower_first(&t, &metric);
+/// assert_eq!(lowered.to_vec(), vec![2.0, 4.0, 3.0, 4.0]);
+///
+/// let _: Tensor<Plane, RaisedIndex<Mixed, U1>> =
+///     Tensor::<Plane, (ContravariantIndex, ContravariantIndex)>::zero(p);
+/// ```
+pub type RaisedIndex<V, I> = Replaced<V, I, ContravariantIndex>;
+
+/// The variance `V` with the index at position `I` made covariant, e.g. the variance of the
+/// result of lowering that index with the metric. See `RaisedIndex` for an example.
+pub type LoweredIndex<V, I> = Replaced<V, I, CovariantIndex>;
+
+impl<W: TensorIndex> ReplaceIndex<U0, W> for CovariantIndex {
+    type Output = W;
+}
+
+impl<W: TensorIndex> ReplaceIndex<U0, W> for ContravariantIndex {
+    type Output = W;
+}
+
+impl<U, V, W> ReplaceIndex<U0, W> for (U, V)
+where
+    U: TensorIndex,
+    V: Variance,
+    W: TensorIndex,
+    Add1<<V as Variance>::Rank>: Unsigned + Add<B1>,
+{
+    type Output = (W, V);
+}
+
+impl<T, B, U, V, W> ReplaceIndex<UInt<T, B>, W> for (U, V)
+where
+    T: Unsigned,
+    B: Bit,
+    U: TensorIndex,
+    W: TensorIndex,
+    UInt<T, B>: Sub<B1>,
+    Sub1<UInt<T, B>>: Unsigned,
+    V: Variance + ReplaceIndex<Sub1<UInt<T, B>>, W>,
+    (U, V): Variance,
+    (U, Replaced<V, Sub1<UInt<T, B>>, W>): Variance,
+{
+    type Output = (U, Replaced<V, Sub1<UInt<T, B>>, W>);
+}
+
 /// An operator trait representing tensor contraction
 ///
 /// Used in tensor inner product
@@ -365,6 +490,24 @@ mod test {
             vec![IndexType::Contravariant, IndexType::Covariant]);
     }
 
+    #[test]
+    fn test_replace() {
+        assert_eq!(
+            <RaisedIndex<CovariantIndex, U0> as Variance>::variance(),
+            vec![IndexType::Contravariant]
+        );
+
+        assert_eq!(
+            <LoweredIndex<(ContravariantIndex, (CovariantIndex, ContravariantIndex)), U2> as Variance>
+                ::variance(),
+            vec![IndexType::Contravariant, IndexType::Covariant, IndexType::Covariant]);
+
+        assert_eq!(
+            <RaisedIndex<(ContravariantIndex, CovariantIndex), U0> as Variance>::variance(),
+            vec![IndexType::Contravariant, IndexType::Covariant]
+        );
+    }
+
     #[test]
     fn test_contract() {
         assert_eq!(
@@ -0,0 +1,124 @@
+//! This module defines the `TensorView` type, a borrowed view of a tensor's coordinates.
+
+use super::{CoordIterator, Tensor, Variance};
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::typenum::{Exp, Pow};
+use core::marker::PhantomData;
+use core::ops::Index;
+use generic_array::{ArrayLength, GenericArray};
+
+/// A view borrowing the coordinates of a tensor.
+///
+/// Parts of the coordinates can be borrowed through a view without copying.
+pub struct TensorView<'a, T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    V::Rank: ArrayLength<usize>,
+{
+    p: &'a Point<T>,
+    x: &'a [f64],
+    _variance: PhantomData<V>,
+}
+
+impl<T, V> Tensor<T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    V::Rank: ArrayLength<usize>,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Returns a view borrowing the coordinates of the tensor.
+    pub fn view(&self) -> TensorView<'_, T, V> {
+        TensorView {
+            p: self.get_point(),
+            x: self.coords_array(),
+            _variance: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, V> TensorView<'a, T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    V::Rank: ArrayLength<usize>,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    /// Returns the point at which the viewed tensor is defined.
+    pub fn get_point(&self) -> &'a Point<T> {
+        self.p
+    }
+
+    /// Returns the coordinates of the viewed tensor, in the same order as
+    /// `Tensor::coords_array`.
+    pub fn coords(&self) -> &'a [f64] {
+        self.x
+    }
+
+    /// Returns the coordinate at the given multi-index.
+    ///
+    /// Panics if the number of indices isn't equal to the rank, or if an index is out of
+    /// range.
+    pub fn get(&self, i: &[usize]) -> f64 {
+        self.x[Tensor::<T, V>::get_coord(i)]
+    }
+
+    /// Returns the coordinates with the first index equal to `i`, which are contiguous in
+    /// memory, without copying them. Panics if the tensor is a scalar.
+    pub fn slice_first(&self, i: usize) -> &'a [f64] {
+        assert!(V::rank() > 0 && i < T::dimension());
+        let len = self.x.len() / T::dimension();
+        &self.x[i * len..(i + 1) * len]
+    }
+
+    /// Returns an iterator over the pairs (multi-index, coordinate) in the storage order.
+    pub fn iter(&self) -> impl Iterator<Item = (GenericArray<usize, V::Rank>, f64)> + '_ {
+        CoordIterator::<V>::new(T::dimension()).zip(self.x.iter().cloned())
+    }
+}
+
+impl<'a, T, V> Clone for TensorView<'a, T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    V::Rank: ArrayLength<usize>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            p: self.p,
+            x: self.x,
+            _variance: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, V> Index<usize> for TensorView<'a, T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    V::Rank: ArrayLength<usize>,
+{
+    type Output = f64;
+
+    fn index(&self, idx: usize) -> &f64 {
+        &self.x[idx]
+    }
+}
+
+impl<'a, 'b, T, V> Index<&'b [usize]> for TensorView<'a, T, V>
+where
+    T: CoordinateSystem,
+    V: Variance,
+    V::Rank: ArrayLength<usize>,
+    T::Dimension: Pow<V::Rank>,
+    Exp<T::Dimension, V::Rank>: ArrayLength<f64>,
+{
+    type Output = f64;
+
+    fn index(&self, idx: &'b [usize]) -> &f64 {
+        &self.x[Tensor::<T, V>::get_coord(idx)]
+    }
+}
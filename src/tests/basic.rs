@@ -1,9 +1,13 @@
 use crate::coordinates::{CoordinateSystem, Point};
-use crate::tensors::{Covector, InvTwoForm, Matrix, Scalar, TwoForm, Vector};
-use crate::typenum::consts::{U0, U1, U2, U4};
+use crate::tensors::{
+    ContravariantIndex, CovariantIndex, Covector, FreeTensor, InvTwoForm, Matrix, MemoryOrder,
+    Scalar, Symmetry, Tensor, TensorError, TwoForm, Vector,
+};
+use crate::typenum::consts::{U0, U1, U2, U3, U4};
+use crate::typenum::{Exp, Pow};
 use crate::{inner, mul};
 use generic_array::arr;
-use generic_array::GenericArray;
+use generic_array::{ArrayLength, GenericArray};
 
 struct Test2;
 impl CoordinateSystem for Test2 {
@@ -27,6 +31,12 @@ fn test_num_coords() {
     assert_eq!(Matrix::<Test4>::get_num_coords(), 16);
 }
 
+#[test]
+fn test_shape() {
+    type Rank3 = Tensor<Test4, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+    assert_eq!(&*Rank3::shape(), &[4, 4, 4]);
+}
+
 #[test]
 fn test_iter_coords() {
     let p1 = Point::new(GenericArray::default());
@@ -47,6 +57,24 @@ fn test_iter_coords() {
     assert_eq!(i, 16);
 }
 
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_iter_coords() {
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+    type Rank3 = Tensor<Test4, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+    let p = Point::new(GenericArray::default());
+    let t = Rank3::from_slice(p, &(0..64).map(|k| k as f64 * 0.5).collect::<Vec<_>>());
+
+    let coords: Vec<_> = t.par_iter_coords().collect();
+    assert_eq!(coords, t.iter_coords().collect::<Vec<_>>());
+
+    let parallel: f64 = t.par_iter_coords().map(|i| t[&*i] * t[&*i]).sum();
+    let serial: f64 = t.iter_coords().map(|i| t[&*i] * t[&*i]).sum();
+    assert_eq!(t.par_iter_coords().len(), 64);
+    assert!((parallel - serial).abs() < 1e-9);
+}
+
 #[test]
 fn test_add() {
     let p = Point::new(GenericArray::default());
@@ -178,3 +206,845 @@ fn test_inverse() {
     assert!((result[2] - 1.5).abs() < epsilon);
     assert!((result[3] + 0.5).abs() < epsilon);
 }
+
+#[test]
+fn test_vec_round_trip() {
+    let p = Point::new(GenericArray::default());
+    let matrix = Matrix::<Test2>::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+
+    let coords = matrix.to_vec();
+    assert_eq!(coords, vec![1.0, 2.0, 3.0, 4.0]);
+
+    let result = Matrix::<Test2>::from_vec(p, coords);
+    assert_eq!(result.coords_array(), matrix.coords_array());
+
+    assert_eq!(
+        Matrix::<Test2>::try_from_vec(p, vec![1.0, 2.0, 3.0]).unwrap_err(),
+        TensorError::WrongLength {
+            expected: 4,
+            found: 3
+        }
+    );
+}
+
+#[test]
+fn test_try_from_slice() {
+    let p = Point::<Test2>::new(GenericArray::default());
+
+    let m = Matrix::try_from_slice(p, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+    assert_eq!(*m.coords_array(), arr![f64; 1.0, 2.0, 3.0, 4.0]);
+
+    assert_eq!(
+        Matrix::try_from_slice(p, &[1.0, 2.0, f64::NAN, 4.0]).unwrap_err(),
+        TensorError::NonFinite { index: 2 }
+    );
+    assert_eq!(
+        Vector::try_from_slice(p, &[f64::INFINITY, 0.0]).unwrap_err(),
+        TensorError::NonFinite { index: 0 }
+    );
+    assert_eq!(
+        Vector::try_from_slice(p, &[1.0, 2.0, 3.0]).unwrap_err(),
+        TensorError::WrongLength {
+            expected: 2,
+            found: 3
+        }
+    );
+}
+
+#[test]
+fn test_free_tensors() {
+    type FreeVector = FreeTensor<Test2, ContravariantIndex>;
+    type FreeCovector = FreeTensor<Test2, CovariantIndex>;
+
+    let a = FreeVector::new(arr![f64; 1.0, 2.0]);
+    let b = FreeVector::from_slice(&[0.5, -1.0]);
+    let sum = a + mul!(FreeVector, f64; b, 2.0);
+    assert_eq!(*sum.coords_array(), arr![f64; 2.0, 0.0]);
+
+    let c = FreeCovector::new(arr![f64; 3.0, 4.0]);
+    let outer = mul!(FreeVector, FreeCovector; a, c);
+    assert_eq!(*outer.coords_array(), arr![f64; 3.0, 4.0, 6.0, 8.0]);
+
+    let p = Point::new(arr![f64; 1.0, -1.0]);
+    let matrix: Matrix<Test2> = outer.anchor(p);
+    assert_eq!(*matrix.get_point(), p);
+    assert_eq!(matrix[&[1, 0][..]], 6.0);
+    assert_eq!(*matrix.into_free().coords_array(), *outer.coords_array());
+}
+
+#[test]
+fn test_diagonal() {
+    let p = Point::new(arr![f64; 1.0, 2.0, 3.0, 4.0]);
+    let diagonal = Matrix::<Test4>::diagonal(p, &[1.0; 4]);
+    assert_eq!(
+        diagonal.coords_array(),
+        Matrix::<Test4>::unit(p).coords_array()
+    );
+
+    type Rank4 = Tensor<
+        Test2,
+        (
+            ContravariantIndex,
+            (CovariantIndex, (CovariantIndex, CovariantIndex)),
+        ),
+    >;
+    let p = Point::new(arr![f64; 0.0, 0.0]);
+    let diagonal = Rank4::diagonal(p, &[2.0, -1.0]);
+    for (i, x) in diagonal.coords_array().iter().enumerate() {
+        let expected = match i {
+            0 => 2.0,
+            15 => -1.0,
+            _ => 0.0,
+        };
+        assert_eq!(*x, expected);
+    }
+    assert_eq!(diagonal[&[1, 1, 1, 1][..]], -1.0);
+}
+
+#[test]
+fn test_dot_and_pair() {
+    let p = Point::<Test2>::new(arr![f64; 1.0, 0.5]);
+    let u = Vector::new(p, arr![f64; 1.0, 2.0]);
+    let v = Vector::new(p, arr![f64; 3.0, -1.0]);
+    let w = Covector::new(p, arr![f64; 3.0, -1.0]);
+
+    // in the Euclidean metric both agree
+    let euclidean = TwoForm::new(p, arr![f64; 1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(u.dot(&v, &euclidean), 1.0);
+    assert_eq!(u.pair(&w), 1.0);
+
+    let metric = TwoForm::new(p, arr![f64; 2.0, 1.0, 1.0, 3.0]);
+    // 2 * 1 * 3 + 1 * 1 * (-1) + 1 * 2 * 3 + 3 * 2 * (-1)
+    assert_eq!(u.dot(&v, &metric), 5.0);
+    assert_eq!(u.dot(&v, &metric), v.dot(&u, &metric));
+}
+
+#[test]
+fn test_project_onto() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let euclidean = TwoForm::new(p, arr![f64; 1.0, 0.0, 0.0, 1.0]);
+    let v = Vector::new(p, arr![f64; 3.0, 4.0]);
+
+    let axis = Vector::new(p, arr![f64; 2.0, 0.0]);
+    let (projection, residual) = v.project_onto(&[axis], &euclidean);
+    assert_eq!(*projection.coords_array(), arr![f64; 3.0, 0.0]);
+    assert_eq!(*residual.coords_array(), arr![f64; 0.0, 4.0]);
+
+    // a degenerate basis only spans the axis
+    let twice = Vector::new(p, arr![f64; -1.0, 0.0]);
+    let (projection, _) = v.project_onto(&[axis, twice], &euclidean);
+    assert_eq!(*projection.coords_array(), arr![f64; 3.0, 0.0]);
+
+    // the projection onto the whole space is the vector itself
+    let other = Vector::new(p, arr![f64; 1.0, 1.0]);
+    let (projection, residual) = v.project_onto(&[axis, other], &euclidean);
+    assert!((projection[0] - 3.0).abs() < 1e-12 && (projection[1] - 4.0).abs() < 1e-12);
+    assert!(residual[0].abs() < 1e-12 && residual[1].abs() < 1e-12);
+
+    let (projection, residual) = v.project_onto(&[], &euclidean);
+    assert_eq!(*projection.coords_array(), arr![f64; 0.0, 0.0]);
+    assert_eq!(*residual.coords_array(), *v.coords_array());
+
+    // with a non-diagonal metric the projection isn't the Euclidean one: g(v, a) / g(a, a)
+    // = 20 / 8, and the residual (-2, 4) is orthogonal to the axis in the sense of g
+    let metric = TwoForm::new(p, arr![f64; 2.0, 1.0, 1.0, 3.0]);
+    let (projection, residual) = v.project_onto(&[axis], &metric);
+    assert_eq!(*projection.coords_array(), arr![f64; 5.0, 0.0]);
+    assert_eq!(*residual.coords_array(), arr![f64; -2.0, 4.0]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_normalized() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let lorentzian = TwoForm::new(p, arr![f64; -1.0, 0.0, 0.0, 1.0]);
+
+    let spacelike = Vector::new(p, arr![f64; 3.0, 5.0]);
+    let unit = spacelike.normalized(&lorentzian).unwrap();
+    assert!((unit.dot(&unit, &lorentzian) - 1.0).abs() < 1e-12);
+    assert!((unit[1] / unit[0] - 5.0 / 3.0).abs() < 1e-12);
+
+    let timelike = Vector::new(p, arr![f64; 2.0, 0.0]);
+    let unit = timelike.normalized(&lorentzian).unwrap();
+    assert_eq!(*unit.coords_array(), arr![f64; 1.0, 0.0]);
+    assert_eq!(unit.dot(&unit, &lorentzian), -1.0);
+
+    let null = Vector::new(p, arr![f64; 0.1, 0.1]);
+    assert!(null.normalized(&lorentzian).is_none());
+    assert!(Vector::zero(p).normalized(&lorentzian).is_none());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_orthonormalize() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let metric = TwoForm::new(p, arr![f64; -2.0, 0.5, 0.5, 1.0]);
+    let mut frame = [
+        Vector::new(p, arr![f64; 1.0, 0.2]),
+        Vector::new(p, arr![f64; 0.3, 2.0]),
+    ];
+    assert!(Vector::orthonormalize(&mut frame, &metric));
+
+    assert!((frame[0].dot(&frame[0], &metric) + 1.0).abs() < 1e-12);
+    assert!((frame[1].dot(&frame[1], &metric) - 1.0).abs() < 1e-12);
+    assert!(frame[0].dot(&frame[1], &metric).abs() < 1e-12);
+
+    // the first vector keeps its direction
+    assert!((frame[0][1] / frame[0][0] - 0.2).abs() < 1e-12);
+
+    let mut dependent = [
+        Vector::new(p, arr![f64; 1.0, 0.2]),
+        Vector::new(p, arr![f64; -2.0, -0.4]),
+    ];
+    assert!(!Vector::orthonormalize(&mut dependent, &metric));
+}
+
+#[test]
+fn test_enforce_symmetry() {
+    let p = Point::new(arr![f64; 1.0, 2.0]);
+    let symmetric = Symmetry::new().symmetric(0, 1);
+    let nearly = TwoForm::<Test2>::from_slice(p, &[1.0, 0.1 + 1e-9, 0.3 - 0.2, 2.0]);
+    assert!(!nearly.has_symmetry(&symmetric, 0.0));
+    assert!(nearly.has_symmetry(&symmetric, 1e-8));
+
+    let exact = nearly.enforce_symmetry(&symmetric);
+    assert!(exact.has_symmetry(&symmetric, 0.0));
+    assert_eq!(exact[1], exact[2]);
+    assert!(exact.components_eq(&nearly, 1e-8));
+
+    // like the Riemann tensor, antisymmetric in both pairs of indices
+    type Rank4 = Tensor<
+        Test2,
+        (
+            CovariantIndex,
+            (CovariantIndex, (CovariantIndex, CovariantIndex)),
+        ),
+    >;
+    let riemann = Symmetry::new().antisymmetric(0, 1).antisymmetric(2, 3);
+    let coords: Vec<f64> = (0..16)
+        .map(|i| (i * i % 7) as f64 + 1e-10 * i as f64)
+        .collect();
+    let t = Rank4::from_slice(p, &coords).enforce_symmetry(&riemann);
+    assert!(t.has_symmetry(&riemann, 0.0));
+    assert_eq!(t[&[0, 0, 1, 0][..]], 0.0);
+    assert_eq!(t[&[0, 1, 0, 1][..]], -t[&[1, 0, 0, 1][..]]);
+    assert_eq!(
+        t.enforce_symmetry(&riemann).coords_array(),
+        t.coords_array()
+    );
+
+    // a tensor symmetric in (0, 1) and antisymmetric in (1, 2) vanishes
+    type Rank3 = Tensor<Test2, (CovariantIndex, (CovariantIndex, CovariantIndex))>;
+    let impossible = Symmetry::new().symmetric(0, 1).antisymmetric(1, 2);
+    let t = Rank3::from_slice(p, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(t.enforce_symmetry(&impossible).count_nonzero(0.0), 0);
+}
+
+crate::coordinate_system!(Declared3, U3);
+
+#[test]
+fn test_coordinate_system_macro() {
+    assert_eq!(Declared3::dimension(), 3);
+    let p = Point::<Declared3>::new(arr![f64; 1.0, 2.0, 3.0]);
+    let v = Vector::new(p, arr![f64; 0.0, 1.0, 0.0]);
+    let t = mul!(_, Covector<Declared3>; v, Covector::new(p, arr![f64; 1.0, 2.0, 3.0]));
+    assert_eq!(t[&[1, 2][..]], 3.0);
+}
+
+#[test]
+fn test_coord_iterator_len() {
+    type Rank3 = Tensor<Test2, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+    let p = Point::new(arr![f64; 0.0, 0.0]);
+    let t = Rank3::zero(p);
+
+    let mut iter = t.iter_coords();
+    assert_eq!(iter.len(), Rank3::get_num_coords());
+    assert_eq!(iter.clone().count(), Rank3::get_num_coords());
+    assert_eq!(iter.next().unwrap(), arr![usize; 0, 0, 0]);
+    assert_eq!(iter.next().unwrap(), arr![usize; 0, 0, 1]);
+    assert_eq!(iter.len(), Rank3::get_num_coords() - 2);
+    assert_eq!(iter.next_back().unwrap(), arr![usize; 1, 1, 1]);
+
+    let scalar = Scalar::<Test2>::zero(p);
+    assert_eq!(scalar.iter_coords().len(), 1);
+    assert_eq!(scalar.iter_coords().count(), 1);
+}
+
+#[test]
+fn test_coord_iterator_reversed() {
+    type Rank3 = Tensor<Test2, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+    let t = Rank3::zero(Point::new(arr![f64; 0.0, 0.0]));
+
+    let forward: Vec<_> = t.iter_coords().collect();
+    let mut backward: Vec<_> = t.iter_coords().rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    // both ends meet in the middle
+    let mut iter = t.iter_coords();
+    assert_eq!(iter.next_back().unwrap(), arr![usize; 1, 1, 1]);
+    assert_eq!(iter.next().unwrap(), arr![usize; 0, 0, 0]);
+    assert_eq!(iter.len(), 6);
+    let rest: Vec<_> = iter.rev().collect();
+    assert_eq!(rest.len(), 6);
+    assert_eq!(rest[0], arr![usize; 1, 1, 0]);
+    assert_eq!(rest[5], arr![usize; 0, 0, 1]);
+
+    let scalar = Scalar::<Test2>::zero(Point::new(arr![f64; 0.0, 0.0]));
+    assert_eq!(scalar.iter_coords().rev().count(), 1);
+}
+
+#[test]
+fn test_apply() {
+    type Form3 = Tensor<Test2, (CovariantIndex, (CovariantIndex, CovariantIndex))>;
+    let p = Point::new(arr![f64; 0.0, 0.0]);
+    let t = Form3::from_slice(p, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let e0 = Vector::new(p, arr![f64; 1.0, 0.0]);
+    let e1 = Vector::new(p, arr![f64; 0.0, 1.0]);
+
+    // on basis vectors, the result is a single component
+    assert_eq!(t.apply(&[&e0, &e0, &e0]), 1.0);
+    assert_eq!(t.apply(&[&e1, &e0, &e1]), 6.0);
+    assert_eq!(t.apply(&[&e0, &e1, &e1]), 4.0);
+
+    let v = Vector::new(p, arr![f64; 1.0, 2.0]);
+    assert_eq!(t.apply(&[&e1, &e1, &v]), 7.0 + 16.0);
+
+    let g = TwoForm::new(p, arr![f64; 2.0, 1.0, 1.0, 3.0]);
+    assert_eq!(g.apply(&[&v, &e1]), v.dot(&e1, &g));
+}
+
+#[test]
+fn test_memory_order() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let m = Matrix::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(m.to_vec_order(MemoryOrder::RowMajor), m.to_vec());
+    assert_eq!(
+        m.to_vec_order(MemoryOrder::ColumnMajor),
+        m.transpose().to_vec_order(MemoryOrder::RowMajor)
+    );
+
+    type Rank3 = Tensor<Test2, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+    let t = Rank3::from_slice(p, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let column_major = t.to_vec_order(MemoryOrder::ColumnMajor);
+    // the component [0, 0, 1] is the fifth one in column-major order
+    assert_eq!(column_major, vec![1.0, 5.0, 3.0, 7.0, 2.0, 6.0, 4.0, 8.0]);
+    let back = Rank3::from_vec_order(p, column_major, MemoryOrder::ColumnMajor);
+    assert_eq!(back.coords_array(), t.coords_array());
+}
+
+#[test]
+fn test_tensor_view() {
+    type Rank3 = Tensor<Test2, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+    let p = Point::new(arr![f64; 1.0, 2.0]);
+    let t = Rank3::from_slice(p, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let view = t.view();
+
+    assert_eq!(view.get_point(), &p);
+    assert_eq!(view.coords(), &t.coords_array()[..]);
+    for i in t.iter_coords() {
+        assert_eq!(view.get(&i), t[&i[..]]);
+        assert_eq!(view[&i[..]], t[&i[..]]);
+        assert_eq!(view[Rank3::get_coord(&i)], t[&i[..]]);
+    }
+    let pairs: Vec<_> = view.iter().collect();
+    assert_eq!(pairs.len(), 8);
+    assert_eq!(pairs[5], (arr![usize; 1, 0, 1], 6.0));
+
+    assert_eq!(view.slice_first(1), &[5.0, 6.0, 7.0, 8.0]);
+}
+
+#[test]
+fn test_diag() {
+    let p = Point::new(arr![f64; 1.0, 2.0]);
+    let m =
+        Tensor::<Test2, (ContravariantIndex, CovariantIndex)>::from_slice(p, &[1.0, 2.0, 3.0, 4.0]);
+    let d = m.diag();
+    assert_eq!(d.get_point(), &p);
+    assert_eq!(d.coords_array(), &arr![f64; 1.0, 4.0]);
+
+    let values = [3.0, -5.0];
+    let diagonal = TwoForm::<Test2>::diagonal(p, &values);
+    assert_eq!(&diagonal.diag().coords_array()[..], &values[..]);
+}
+
+#[test]
+fn test_set_diagonal() {
+    let p = Point::new(arr![f64; 1.0, 2.0]);
+    let mut m = TwoForm::<Test2>::from_slice(p, &[1.0, 2.0, 3.0, 4.0]);
+    m.set_diagonal(&Vector::<Test2>::from_slice(p, &[-1.0, 5.0]));
+    assert_eq!(m.coords_array(), &arr![f64; -1.0, 2.0, 3.0, 5.0]);
+
+    let d = m.diag();
+    m.set_diagonal(&d);
+    assert_eq!(m.coords_array(), &arr![f64; -1.0, 2.0, 3.0, 5.0]);
+}
+
+#[test]
+fn test_frobenius_inner() {
+    let p = Point::new(arr![f64; 1.0, 2.0]);
+    let a = TwoForm::<Test2>::from_slice(p, &[1.0, -2.0, 3.0, 0.5]);
+    let b = TwoForm::<Test2>::from_slice(p, &[2.0, 1.0, -1.0, 4.0]);
+    assert_eq!(a.frobenius_inner(&b), -1.0);
+    assert_eq!(a.frobenius_inner(&b), b.frobenius_inner(&a));
+    assert_eq!(a.frobenius_inner(&a), 14.25);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_frobenius_norm() {
+    let p = Point::new(arr![f64; 1.0, 2.0]);
+    let a = TwoForm::<Test2>::from_slice(p, &[1.0, -2.0, 3.0, 0.5]);
+    let norm = a.frobenius_norm();
+    assert!((a.frobenius_inner(&a) - norm * norm).abs() < 1e-12);
+    assert_eq!(
+        Vector::<Test2>::from_slice(p, &[3.0, 4.0]).frobenius_norm(),
+        5.0
+    );
+}
+
+#[test]
+fn test_add_scaled() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let y: TwoForm<Test2> = TwoForm::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+    let x: TwoForm<Test2> = TwoForm::new(p, arr![f64; 0.5, -1.0, 0.25, 2.0]);
+
+    let mut result = y;
+    result.add_scaled(-3.0, &x);
+    let expected = y + mul!(TwoForm<Test2>, f64; x, -3.0);
+    assert_eq!(*result.coords_array(), *expected.coords_array());
+}
+
+#[test]
+fn test_assign_references() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let corrections = [
+        Vector::new(p, arr![f64; 1.0, 2.0]),
+        Vector::new(p, arr![f64; -0.5, 4.0]),
+        Vector::new(p, arr![f64; 3.0, 0.0]),
+    ];
+
+    let mut acc = Vector::<Test2>::zero(p);
+    for c in &corrections {
+        acc += c;
+    }
+    assert_eq!(*acc.coords_array(), arr![f64; 3.5, 6.0]);
+
+    for c in &corrections[1..] {
+        acc -= c;
+    }
+    assert_eq!(*acc.coords_array(), *corrections[0].coords_array());
+}
+
+#[test]
+fn test_components_eq() {
+    let a = Vector::new(
+        Point::<Test2>::new(arr![f64; 0.0, 0.0]),
+        arr![f64; 1.0, 2.0],
+    );
+    let b = Vector::new(
+        Point::<Test2>::new(arr![f64; 5.0, 1.0]),
+        arr![f64; 1.0, 2.0 + 1e-13],
+    );
+    assert!(a.components_eq(&b, 1e-12));
+    assert!(!a.components_eq(&b, 0.0));
+    let c = Vector::new(
+        Point::<Test2>::new(arr![f64; 0.0, 0.0]),
+        arr![f64; 1.0, 2.5],
+    );
+    assert!(!a.components_eq(&c, 1e-12));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_stats() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let t = TwoForm::new(p, arr![f64; 2.0, 4.0, 4.0, 6.0]);
+    let stats = t.stats();
+    assert_eq!(stats.min, 2.0);
+    assert_eq!(stats.max, 6.0);
+    assert_eq!(stats.mean, 4.0);
+    assert!((stats.std - 2.0f64.sqrt()).abs() < 1e-15);
+}
+
+#[test]
+fn test_raise_lower_index_with() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let euclidean = TwoForm::new(p, arr![f64; 1.0, 0.0, 0.0, 1.0]);
+    let inv_euclidean = InvTwoForm::new(p, arr![f64; 1.0, 0.0, 0.0, 1.0]);
+    let m = Matrix::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+
+    let lowered = m
+        .lower_index_with::<(CovariantIndex, CovariantIndex)>(&euclidean, 0)
+        .unwrap();
+    assert_eq!(*lowered.coords_array(), *m.coords_array());
+    let raised = m
+        .raise_index_with::<(ContravariantIndex, ContravariantIndex)>(&inv_euclidean, 1)
+        .unwrap();
+    assert_eq!(*raised.coords_array(), *m.coords_array());
+
+    // g = diag(2, 3) scales the rows when lowering the first index
+    let metric = TwoForm::new(p, arr![f64; 2.0, 0.0, 0.0, 3.0]);
+    let lowered = m
+        .lower_index_with::<(CovariantIndex, CovariantIndex)>(&metric, 0)
+        .unwrap();
+    assert_eq!(*lowered.coords_array(), arr![f64; 2.0, 4.0, 9.0, 12.0]);
+
+    let v = Vector::new(p, arr![f64; 1.0, -1.0]);
+    let w = v.lower_index_with::<CovariantIndex>(&metric, 0).unwrap();
+    assert_eq!(*w.coords_array(), arr![f64; 2.0, -3.0]);
+
+    assert_eq!(
+        m.lower_index_with::<(CovariantIndex, CovariantIndex)>(&metric, 1)
+            .unwrap_err(),
+        TensorError::InvalidIndex { index: 1 }
+    );
+    let q = Point::new(arr![f64; 1.0, 0.0]);
+    assert!(matches!(
+        m.lower_index_with::<(CovariantIndex, CovariantIndex)>(
+            &TwoForm::new(q, arr![f64; 1.0, 0.0, 0.0, 1.0]),
+            0
+        ),
+        Err(TensorError::PointMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_raise_lower_indices() {
+    let p = Point::<Test2>::new(arr![f64; 0.0, 0.0]);
+    let metric = TwoForm::new(p, arr![f64; 2.0, 0.0, 0.0, 3.0]);
+    let inv_metric = InvTwoForm::new(p, arr![f64; 0.5, 0.0, 0.0, 1.0 / 3.0]);
+    let t = InvTwoForm::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+
+    // g_ik g_jl T^kl
+    let lowered = t
+        .lower_indices::<(CovariantIndex, CovariantIndex)>(&metric, &[0, 1])
+        .unwrap();
+    assert_eq!(*lowered.coords_array(), arr![f64; 4.0, 12.0, 18.0, 36.0]);
+    let chained = t
+        .lower_index_with::<(CovariantIndex, ContravariantIndex)>(&metric, 0)
+        .unwrap()
+        .lower_index_with::<(CovariantIndex, CovariantIndex)>(&metric, 1)
+        .unwrap();
+    assert_eq!(*chained.coords_array(), *lowered.coords_array());
+
+    let raised = lowered
+        .raise_indices::<(ContravariantIndex, ContravariantIndex)>(&inv_metric, &[1, 0])
+        .unwrap();
+    for (x, y) in raised.coords_array().iter().zip(t.coords_array().iter()) {
+        assert!((x - y).abs() < 1e-14);
+    }
+
+    assert_eq!(
+        t.lower_indices::<(CovariantIndex, CovariantIndex)>(&metric, &[1, 1])
+            .unwrap_err(),
+        TensorError::DuplicateIndex { index: 1 }
+    );
+
+    // a result variance which doesn't match the changed indices is an error, too
+    assert_eq!(
+        t.lower_indices::<(CovariantIndex, ContravariantIndex)>(&metric, &[0, 1])
+            .unwrap_err(),
+        TensorError::InvalidIndex { index: 1 }
+    );
+    assert_eq!(
+        t.lower_index_with::<(ContravariantIndex, CovariantIndex)>(&metric, 0)
+            .unwrap_err(),
+        TensorError::InvalidIndex { index: 0 }
+    );
+    assert_eq!(
+        t.lower_index_with::<CovariantIndex>(&metric, 0)
+            .unwrap_err(),
+        TensorError::InvalidIndex { index: 1 }
+    );
+}
+
+#[test]
+fn test_basis_tensor() {
+    type Rank3 = Tensor<Test2, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+    let p = Point::new(GenericArray::default());
+    let basis = Rank3::basis_tensor(p, &[1, 0, 1]);
+
+    for i in 0..2 {
+        for j in 0..2 {
+            for k in 0..2 {
+                let idx: &[usize] = &[i, j, k];
+                let expected = if (i, j, k) == (1, 0, 1) { 1.0 } else { 0.0 };
+                assert_eq!(basis[idx], expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_wrapped_index() {
+    let p = Point::new(GenericArray::default());
+    let mut matrix = Matrix::<Test4>::from_slice(p, &(0..16).map(|x| x as f64).collect::<Vec<_>>());
+
+    assert_eq!(Matrix::<Test4>::get_coord_wrapped(&[-1, 0]), 12);
+    assert_eq!(matrix.get_wrapped(&[-1, -1]), 15.0);
+    assert_eq!(matrix.get_wrapped(&[4, -5]), matrix.get_wrapped(&[0, 3]));
+
+    *matrix.get_wrapped_mut(&[0, -1]) = -1.0;
+    let idx: &[usize] = &[0, 3];
+    assert_eq!(matrix[idx], -1.0);
+}
+
+#[test]
+fn test_reinterpret_variance() {
+    let p = Point::new(GenericArray::default());
+    let form = TwoForm::<Test2>::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+
+    let matrix = unsafe { form.reinterpret_variance::<(CovariantIndex, ContravariantIndex)>() };
+    assert_eq!(matrix.coords_array(), form.coords_array());
+    assert_eq!(matrix.get_point(), form.get_point());
+}
+
+struct Labeled;
+impl CoordinateSystem for Labeled {
+    type Dimension = U2;
+
+    fn coordinate_labels() -> &'static [&'static str] {
+        &["time", "radial"]
+    }
+}
+
+#[test]
+fn test_component_by_labels() {
+    let p = Point::new(GenericArray::default());
+    let matrix = Matrix::<Labeled>::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+
+    assert_eq!(
+        matrix.component_by_labels(&[("time", 0), ("radial", 1)]),
+        Some(2.0)
+    );
+    assert_eq!(
+        matrix.component_by_labels(&[("time", 1), ("radial", 0)]),
+        Some(3.0)
+    );
+    assert_eq!(
+        matrix.component_by_labels(&[("radial", 0), ("radial", 1)]),
+        Some(4.0)
+    );
+    assert_eq!(
+        matrix.component_by_labels(&[("angle", 0), ("radial", 1)]),
+        None
+    );
+    assert_eq!(
+        matrix.component_by_labels(&[("time", 0), ("radial", 0)]),
+        None
+    );
+    assert_eq!(matrix.component_by_labels(&[("time", 0)]), None);
+}
+
+#[test]
+fn test_equals_symmetrized() {
+    let p = Point::new(GenericArray::default());
+    let form1 = TwoForm::<Test2>::new(p, arr![f64; 1.0, 2.0, 4.0, 3.0]);
+    let form2 = TwoForm::<Test2>::new(p, arr![f64; 1.0, 4.0, 2.0, 3.0]);
+    let form3 = TwoForm::<Test2>::new(p, arr![f64; 1.0, 3.0, 4.0, 3.0]);
+
+    assert!(form1.equals_symmetrized(&form2, 1e-12));
+    assert!(!form1.equals_symmetrized(&form3, 1e-12));
+
+    let sym = form1.symmetrize();
+    assert_eq!(sym[1], 3.0);
+    assert_eq!(sym[2], 3.0);
+}
+
+#[test]
+fn test_point_tolerance() {
+    let p = Point::<Test2>::new(arr![f64; 0.1 + 0.2, 1.0]);
+    let q = Point::<Test2>::new(arr![f64; 0.3, 1.0 + 1e-15]);
+    assert!(p != q);
+    assert!(p.approx_eq(&q));
+
+    let a = Vector::<Test2>::new(p, arr![f64; 1.0, 2.0]);
+    let b = Vector::<Test2>::new(q, arr![f64; 3.0, 4.0]);
+    let sum = a + b;
+    assert_eq!(*sum.coords_array(), arr![f64; 4.0, 6.0]);
+}
+
+#[test]
+#[should_panic]
+fn test_point_mismatch() {
+    let a = Vector::<Test2>::new(Point::new(arr![f64; 0.0, 1.0]), arr![f64; 1.0, 2.0]);
+    let b = Vector::<Test2>::new(Point::new(arr![f64; 0.0, 1.001]), arr![f64; 3.0, 4.0]);
+    let _ = a + b;
+}
+
+#[test]
+fn test_try_add() {
+    let p = Point::new(arr![f64; 0.0, 1.0]);
+    let q = Point::new(arr![f64; 0.0, 1.001]);
+    let a = Vector::<Test2>::new(p, arr![f64; 1.0, 2.0]);
+    let b = Vector::<Test2>::new(p, arr![f64; 3.0, 4.0]);
+    let c = Vector::<Test2>::new(q, arr![f64; 3.0, 4.0]);
+
+    assert_eq!(*a.try_add(b).unwrap().coords_array(), arr![f64; 4.0, 6.0]);
+    assert_eq!(*a.try_sub(b).unwrap().coords_array(), arr![f64; -2.0, -2.0]);
+
+    let mismatch = TensorError::PointMismatch {
+        left: vec![0.0, 1.0],
+        right: vec![0.0, 1.001],
+    };
+    assert_eq!(a.try_add(c).unwrap_err(), mismatch);
+    assert_eq!(a.try_sub(c).unwrap_err(), mismatch);
+    let mut d = a;
+    assert_eq!(d.try_add_scaled(2.0, &c).unwrap_err(), mismatch);
+    assert_eq!(d.coords_array(), a.coords_array());
+}
+
+#[test]
+fn test_fused_ops() {
+    let p = Point::new(GenericArray::default());
+    let a = Matrix::<Test2>::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+    let b = Matrix::<Test2>::new(p, arr![f64; 0.5, -1.0, 2.5, 0.0]);
+    let c = Matrix::<Test2>::new(p, arr![f64; 3.0, 1.0, -2.0, 1.5]);
+
+    // a * 2.0 + b - c
+    let naive = mul!(_, f64; a, 2.0) + b - c;
+
+    let mut fused = a;
+    fused.mul_add_assign(2.0, &b);
+    fused.add_scaled(-1.0, &c);
+
+    assert_eq!(fused.coords_array(), naive.coords_array());
+}
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+#[test]
+fn test_componentwise_parity() {
+    // 27 coordinates - not a multiple of any reasonable vector width
+    type Rank3 = Tensor<Test3, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+    let p = Point::new(GenericArray::default());
+    let a_coords: Vec<f64> = (0..27).map(|i| (i as f64).sin()).collect();
+    let b_coords: Vec<f64> = (0..27).map(|i| (i as f64 * 0.3).cos()).collect();
+    let a = Rank3::from_slice(p, &a_coords);
+    let b = Rank3::from_slice(p, &b_coords);
+
+    let sum = a + b;
+    let diff = a - b;
+    let prod = mul!(_, f64; a, 1.7);
+    let quot = a / 3.1;
+
+    for i in 0..27 {
+        assert_eq!(sum[i], a_coords[i] + b_coords[i]);
+        assert_eq!(diff[i], a_coords[i] - b_coords[i]);
+        assert_eq!(prod[i], a_coords[i] * 1.7);
+        assert_eq!(quot[i], a_coords[i] / 3.1);
+    }
+}
+
+#[test]
+fn test_variance_array() {
+    type Rank3 = Tensor<Test4, (ContravariantIndex, (CovariantIndex, ContravariantIndex))>;
+    assert_eq!(&*Rank3::variance_array(), &Rank3::get_variance()[..]);
+    assert_eq!(
+        &*Matrix::<Test2>::variance_array(),
+        &Matrix::<Test2>::get_variance()[..]
+    );
+}
+
+#[test]
+fn test_sym_product() {
+    let p = Point::new(GenericArray::default());
+    let vector1 = Vector::<Test2>::new(p, arr![f64; 1.0, 2.0]);
+    let vector2 = Vector::<Test2>::new(p, arr![f64; 3.0, 4.0]);
+
+    let result = vector1.sym_product::<ContravariantIndex>(&vector2);
+
+    assert_eq!(result[0], 3.0);
+    assert_eq!(result[1], 5.0);
+    assert_eq!(result[2], 5.0);
+    assert_eq!(result[3], 8.0);
+}
+
+#[test]
+fn test_determinant() {
+    let p = Point::new(GenericArray::default());
+    let matrix = Matrix::<Test2>::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+    assert!((matrix.determinant() + 2.0).abs() < 1e-12);
+
+    let p = Point::new(GenericArray::default());
+    let matrix = Matrix::<Test3>::from_slice(p, &[0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 3.0]);
+    assert!((matrix.determinant() + 3.0).abs() < 1e-12);
+
+    let singular = Matrix::<Test3>::zero(p);
+    assert_eq!(singular.determinant(), 0.0);
+
+    // rank deficient without a zero row - the last pivot is only a rounding error
+    let singular = Matrix::<Test2>::new(
+        Point::new(GenericArray::default()),
+        arr![f64; 0.1, 0.3, 0.7, 2.1],
+    );
+    assert_eq!(singular.determinant(), 0.0);
+    let singular = Matrix::<Test3>::from_slice(p, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_eq!(singular.determinant(), 0.0);
+    assert!(singular.inverse().is_none());
+}
+
+fn check_inverse<T: CoordinateSystem>()
+where
+    T::Dimension: Pow<U2> + Pow<U1>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+    Exp<T::Dimension, U1>: ArrayLength<f64>,
+{
+    let n = T::dimension();
+    // a diagonally dominant, hence invertible, matrix
+    let coords: Vec<f64> = (0..n * n)
+        .map(|k| {
+            if k / n == k % n {
+                10.0
+            } else {
+                (k as f64).sin()
+            }
+        })
+        .collect();
+    let p = Point::new(GenericArray::default());
+    let matrix = Matrix::<T>::from_slice(p, &coords);
+    let inverse = matrix.inverse().unwrap();
+
+    for i in 0..n {
+        for k in 0..n {
+            let product: f64 = (0..n)
+                .map(|j| matrix[&[i, j][..]] * inverse[&[j, k][..]])
+                .sum();
+            let expected = if i == k { 1.0 } else { 0.0 };
+            assert!((product - expected).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_inverse_dimensions() {
+    check_inverse::<Test2>();
+    check_inverse::<Test3>();
+    check_inverse::<Test4>();
+}
+
+#[test]
+fn test_condition_number() {
+    let p = Point::new(GenericArray::default());
+    let unit = Matrix::<Test2>::unit(p);
+    assert!((unit.condition_number() - 1.0).abs() < 1e-12);
+
+    let well = Matrix::<Test2>::new(p, arr![f64; 2.0, 1.0, 1.0, 3.0]);
+    assert!(well.condition_number() < 10.0);
+
+    let ill = Matrix::<Test2>::new(p, arr![f64; 1.0, 1.0, 1.0, 1.0 + 1e-10]);
+    assert!(ill.condition_number() > 1e9);
+
+    let singular = Matrix::<Test2>::new(p, arr![f64; 0.0, 0.0, 1.0, 2.0]);
+    assert_eq!(singular.condition_number(), f64::INFINITY);
+
+    // singular without a zero row
+    let singular = Matrix::<Test2>::new(p, arr![f64; 1.0, 2.0, 2.0, 4.0]);
+    assert_eq!(singular.condition_number(), f64::INFINITY);
+    let singular = Matrix::<Test2>::new(p, arr![f64; 0.1, 0.3, 0.7, 2.1]);
+    assert_eq!(singular.condition_number(), f64::INFINITY);
+}
@@ -0,0 +1,114 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::metric::MetricSystem;
+use crate::num_complex::Complex;
+use crate::systems::{Minkowski, MostlyMinus};
+use crate::tensors::{ComplexTensor, ContravariantIndex, CovariantIndex, InvTwoForm, TwoForm};
+use crate::typenum::consts::{U2, U3};
+use crate::typenum::{Exp, Pow};
+use generic_array::{arr, ArrayLength, GenericArray};
+
+struct Test2;
+impl CoordinateSystem for Test2 {
+    type Dimension = U2;
+}
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+type ComplexMatrix = ComplexTensor<Test2, (ContravariantIndex, CovariantIndex)>;
+
+#[test]
+fn test_conjugate() {
+    let p = Point::new(GenericArray::default());
+    let matrix = ComplexMatrix::from_slice(
+        p,
+        &[
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 3.0),
+            Complex::new(2.0, -3.0),
+            Complex::new(4.0, 0.0),
+        ],
+    );
+
+    let result = matrix.conjugate();
+
+    assert_eq!(result.get(&[0, 1]), Complex::new(2.0, -3.0));
+    assert_eq!(result.get(&[1, 0]), Complex::new(2.0, 3.0));
+    assert_eq!(result.get(&[1, 1]), Complex::new(4.0, 0.0));
+}
+
+#[test]
+fn test_hermitian() {
+    let p = Point::new(GenericArray::default());
+    let mut matrix = ComplexMatrix::from_slice(
+        p,
+        &[
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 3.0),
+            Complex::new(2.0, -3.0),
+            Complex::new(4.0, 0.0),
+        ],
+    );
+
+    assert!(matrix.is_hermitian(1e-12));
+
+    matrix.set(&[0, 0], Complex::new(1.0, 1.0));
+    assert!(!matrix.is_hermitian(1e-12));
+}
+
+// Checks that {γ^μ, γ^ν} = 2g^{μν} I
+fn check_clifford<T>(
+    gammas: &[ComplexTensor<T, (ContravariantIndex, CovariantIndex)>],
+    inv_metric: &InvTwoForm<T>,
+) where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U2>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+{
+    let n = T::dimension();
+    assert_eq!(gammas.len(), n);
+    for mu in 0..n {
+        for nu in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    let anticommutator: Complex<f64> = (0..n)
+                        .map(|k| {
+                            gammas[mu].get(&[i, k]) * gammas[nu].get(&[k, j])
+                                + gammas[nu].get(&[i, k]) * gammas[mu].get(&[k, j])
+                        })
+                        .sum();
+                    let expected = if i == j {
+                        2.0 * inv_metric[&[mu, nu][..]]
+                    } else {
+                        0.0
+                    };
+                    assert!((anticommutator - expected).norm() < 1e-12);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gamma_matrices_minkowski() {
+    let p = Point::new(arr![f64; 0.0, 0.0, 0.0, 0.0]);
+    let metric = Minkowski::<MostlyMinus>::g(&p);
+    let gammas = ComplexTensor::gamma_matrices(&metric).unwrap();
+    check_clifford(&gammas, &Minkowski::<MostlyMinus>::inv_g(&p));
+}
+
+#[test]
+fn test_gamma_matrices_general() {
+    let p = Point::new(GenericArray::default());
+    let metric = TwoForm::<Test2>::from_slice(p, &[2.0, 1.0, 1.0, -3.0]);
+    let gammas = ComplexTensor::gamma_matrices(&metric).unwrap();
+    check_clifford(&gammas, &metric.inverse().unwrap());
+
+    let degenerate = TwoForm::<Test2>::from_slice(p, &[1.0, 1.0, 1.0, 1.0]);
+    assert!(ComplexTensor::gamma_matrices(&degenerate).is_none());
+
+    let euclidean3 = TwoForm::<Test3>::unit(Point::new(GenericArray::default()));
+    assert!(ComplexTensor::gamma_matrices(&euclidean3).is_none());
+}
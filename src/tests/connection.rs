@@ -0,0 +1,98 @@
+use crate::connection::{torsion, Connection, LeviCivita};
+use crate::coordinates::Point;
+use crate::field::covariant_hessian_with;
+use crate::geodesic::parallel_transport_with;
+use crate::metric::MetricSystem;
+use crate::systems::{Cartesian, S2};
+use crate::tensors::{ContravariantIndex, CovariantIndex, Scalar, Tensor, Vector};
+use crate::typenum::consts::U2;
+use generic_array::arr;
+
+type Christoffel = Tensor<S2, (ContravariantIndex, (CovariantIndex, CovariantIndex))>;
+
+#[test]
+fn test_levi_civita_torsion() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    let torsion = torsion(&S2::christoffel, &p);
+    assert!(torsion.coords_array().iter().all(|x| x.abs() < 1e-12));
+}
+
+#[test]
+fn test_asymmetric_torsion() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    // Γ^0_{01} = 1, Γ^0_{10} = 0.5, Γ^1_{11} = 2
+    let connection =
+        |p: &Point<S2>| Christoffel::new(*p, arr![f64; 0.0, 1.0, 0.5, 0.0, 0.0, 0.0, 0.0, 2.0]);
+    let torsion = torsion(&connection, &p);
+    assert_eq!(
+        *torsion.coords_array(),
+        arr![f64; 0.0, 0.5, -0.5, 0.0, 0.0, 0.0, 0.0, 0.0]
+    );
+}
+
+// A constant connection on the plane with Γ^0_{11} = Γ^1_{00} = 1, which doesn't preserve
+// the Euclidean metric
+struct Constant;
+
+impl Connection<Cartesian<U2>> for Constant {
+    fn christoffel(
+        point: &Point<Cartesian<U2>>,
+    ) -> Tensor<Cartesian<U2>, (ContravariantIndex, (CovariantIndex, CovariantIndex))> {
+        Tensor::<Cartesian<U2>, (ContravariantIndex, (CovariantIndex, CovariantIndex))>::new(
+            *point,
+            arr![f64; 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0],
+        )
+    }
+}
+
+#[test]
+fn test_non_metric_curvature() {
+    let p = Point::new(arr![f64; 0.5, -1.0]);
+    let riemann = Constant::riemann(&p);
+
+    // R^ρ_{σμν} = Γ^ρ_{μλ} Γ^λ_{νσ} - Γ^ρ_{νλ} Γ^λ_{μσ} for a constant connection
+    for i in riemann.iter_coords() {
+        let expected = match (i[0], i[1], i[2], i[3]) {
+            (0, 0, 0, 1) | (1, 1, 1, 0) => -1.0,
+            (0, 0, 1, 0) | (1, 1, 0, 1) => 1.0,
+            _ => 0.0,
+        };
+        assert_eq!(riemann[&*i], expected);
+    }
+    assert!(Constant::torsion(&p)
+        .coords_array()
+        .iter()
+        .all(|x| *x == 0.0));
+}
+
+#[test]
+fn test_non_metric_derivatives() {
+    let p = Point::new(arr![f64; 0.5, -1.0]);
+
+    // f = x has a vanishing Hessian, so ∇_i ∇_j f = -Γ^0_ij
+    let f = |p: &Point<Cartesian<U2>>| Scalar::<Cartesian<U2>>::new(*p, arr![f64; p[0]]);
+    let hessian = covariant_hessian_with::<Constant, _, _>(&f, &p);
+    let expected = [0.0, 0.0, 0.0, -1.0];
+    for (x, y) in hessian.coords_array().iter().zip(expected.iter()) {
+        assert!((x - y).abs() < 1e-6);
+    }
+
+    // along the x axis, dV^0/dλ = 0 and dV^1/dλ = -V^0
+    let v = Vector::<Cartesian<U2>>::new(p, arr![f64; 1.0, 0.0]);
+    let target = Point::new(arr![f64; 1.5, -1.0]);
+    let result =
+        parallel_transport_with::<Constant, Cartesian<U2>, ContravariantIndex>(&v, &target, 10);
+    assert!((result[0] - 1.0).abs() < 1e-12);
+    assert!((result[1] + 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_levi_civita_curvature() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    let ricci = LeviCivita::<S2>::ricci(&p);
+    let metric = S2::g(&p);
+    // the Ricci tensor of the unit sphere is equal to the metric
+    for i in 0..4 {
+        assert!((ricci[i] - metric[i]).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::tensors::kernels::Powers;
+use crate::tensors::{ContravariantIndex as Up, CovariantIndex as Lo, InnerProduct, Tensor};
+use crate::typenum::consts::{U0, U1, U2, U3, U4};
+use crate::typenum::Unsigned;
+use crate::{inner, mul};
+use generic_array::GenericArray;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::reference::{reference_inner_product, reference_outer, reference_trace};
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+fn random_coords<R: Rng>(rng: &mut R, n: usize) -> Vec<f64> {
+    (0..n).map(|_| rng.gen_range(-1.0, 1.0)).collect()
+}
+
+fn assert_close(result: &[f64], expected: &[f64]) {
+    assert_eq!(result.len(), expected.len());
+    for (x, y) in result.iter().zip(expected.iter()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+}
+
+macro_rules! check_trace {
+    ($V: ty; $Ul: ty, $Uh: ty) => {
+        check_trace!($V; $Ul, $Uh; (0..Tensor::<Test3, $V>::get_num_coords())
+            .map(|i| (i as f64 * 0.7).sin())
+            .collect::<Vec<f64>>())
+    };
+    ($V: ty; $Ul: ty, $Uh: ty; $coords: expr) => {{
+        let p = Point::new(GenericArray::default());
+        let coords: Vec<f64> = $coords;
+        let tensor = Tensor::<Test3, $V>::from_slice(p, &coords);
+        let rank = Tensor::<Test3, $V>::get_rank();
+
+        let result = tensor.trace::<$Ul, $Uh>();
+        let expected = reference_trace(&coords, 3, rank, <$Ul>::to_usize(), <$Uh>::to_usize());
+
+        assert_close(result.coords_array(), &expected);
+    }};
+}
+
+macro_rules! check_inner {
+    ($V1: ty, $V2: ty; $Ul: ty, $Uh: ty; $rng: expr) => {{
+        let p = Point::new(GenericArray::default());
+        let coords1 = random_coords($rng, Tensor::<Test3, $V1>::get_num_coords());
+        let coords2 = random_coords($rng, Tensor::<Test3, $V2>::get_num_coords());
+        let tensor1 = Tensor::<Test3, $V1>::from_slice(p, &coords1);
+        let tensor2 = Tensor::<Test3, $V2>::from_slice(p, &coords2);
+        let rank1 = Tensor::<Test3, $V1>::get_rank();
+        let rank2 = Tensor::<Test3, $V2>::get_rank();
+
+        let outer = mul!(Tensor<Test3, $V1>, Tensor<Test3, $V2>; tensor1, tensor2);
+        let expected = reference_outer(&coords1, &coords2, 3, rank1, rank2);
+        assert_close(outer.coords_array(), &expected);
+
+        let result = <Tensor<Test3, $V1> as InnerProduct<Tensor<Test3, $V2>, $Ul, $Uh>>
+            ::inner_product(tensor1, tensor2);
+        let expected = reference_inner_product(
+            &coords1,
+            &coords2,
+            3,
+            rank1,
+            rank2,
+            <$Ul>::to_usize(),
+            <$Uh>::to_usize(),
+        );
+        assert_close(result.coords_array(), &expected);
+    }};
+}
+
+#[test]
+fn test_trace_rank2() {
+    check_trace!((Up, Lo); U0, U1);
+    check_trace!((Lo, Up); U0, U1);
+}
+
+#[test]
+fn test_trace_scalar() {
+    let p = Point::new(GenericArray::default());
+    assert_eq!(Tensor::<Test3, (Up, Lo)>::unit(p).trace_scalar(), 3.0);
+
+    let t =
+        Tensor::<Test3, (Lo, Up)>::from_slice(p, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_eq!(t.trace_scalar(), *t.trace::<U0, U1>());
+}
+
+#[test]
+fn test_trace_of_product() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let p = Point::new(GenericArray::default());
+    let a = Tensor::<Test3, (Up, Lo)>::from_slice(p, &random_coords(&mut rng, 9));
+    let b = Tensor::<Test3, (Up, Lo)>::from_slice(p, &random_coords(&mut rng, 9));
+    let product = inner!(Tensor<Test3, (Up, Lo)>, Tensor<Test3, (Up, Lo)>; U1, U2; a, b);
+    assert!((a.trace_of_product(&b) - product.trace_scalar()).abs() < 1e-12);
+    // the same through the general contractions: the matrix product as a trace of the outer
+    // product, followed by its trace
+    let outer = mul!(Tensor<Test3, (Up, Lo)>, Tensor<Test3, (Up, Lo)>; a, b);
+    let traced = *outer.trace::<U1, U2>().trace::<U0, U1>();
+    assert!((a.trace_of_product(&b) - traced).abs() < 1e-12);
+    assert!((a.trace_of_product(&b) - *product.trace::<U0, U1>()).abs() < 1e-12);
+
+    let g = Tensor::<Test3, (Lo, Lo)>::from_slice(p, &random_coords(&mut rng, 9));
+    let h = Tensor::<Test3, (Up, Up)>::from_slice(p, &random_coords(&mut rng, 9));
+    let product = inner!(Tensor<Test3, (Lo, Lo)>, Tensor<Test3, (Up, Up)>; U1, U2; g, h);
+    assert!((g.trace_of_product(&h) - product.trace_scalar()).abs() < 1e-12);
+    let outer = mul!(Tensor<Test3, (Lo, Lo)>, Tensor<Test3, (Up, Up)>; g, h);
+    let traced = *outer.trace::<U1, U2>().trace::<U0, U1>();
+    assert!((g.trace_of_product(&h) - traced).abs() < 1e-12);
+}
+
+#[test]
+fn test_trace_rank3() {
+    check_trace!((Up, (Lo, Lo)); U0, U1);
+    check_trace!((Up, (Lo, Lo)); U0, U2);
+    check_trace!((Lo, (Up, Lo)); U1, U2);
+}
+
+#[test]
+fn test_trace_rank4() {
+    check_trace!((Up, (Lo, (Lo, Lo))); U0, U1);
+    check_trace!((Up, (Lo, (Lo, Lo))); U0, U2);
+    check_trace!((Up, (Lo, (Lo, Lo))); U0, U3);
+    check_trace!((Lo, (Up, (Lo, Lo))); U1, U2);
+    check_trace!((Lo, (Up, (Lo, Lo))); U1, U3);
+    check_trace!((Lo, (Lo, (Up, Lo))); U2, U3);
+}
+
+#[test]
+fn test_trace_rank5() {
+    check_trace!((Up, (Lo, (Lo, (Lo, Lo)))); U0, U4);
+    check_trace!((Lo, (Lo, (Up, (Lo, Lo)))); U2, U3);
+    check_trace!((Lo, (Up, (Lo, (Lo, Lo)))); U1, U4);
+}
+
+#[test]
+fn test_powers() {
+    let powers = Powers::new(3, 6);
+    for i in 0..6 {
+        assert_eq!(powers[i], 3usize.pow(i as u32));
+    }
+}
+
+const TRIALS: usize = 20;
+
+#[test]
+fn test_trace_random() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..TRIALS {
+        check_trace!((Up, (Lo, Lo)); U0, U2; random_coords(&mut rng, 27));
+        check_trace!((Up, (Lo, (Lo, Lo))); U0, U3; random_coords(&mut rng, 81));
+        check_trace!((Lo, (Up, (Lo, Lo))); U1, U3; random_coords(&mut rng, 81));
+    }
+}
+
+#[test]
+fn test_inner_product_random() {
+    let mut rng = StdRng::seed_from_u64(2);
+    for _ in 0..TRIALS {
+        // both contracted indices in the first tensor
+        check_inner!((Up, Lo), Up; U0, U1; &mut rng);
+        check_inner!((Lo, (Up, Lo)), Lo; U0, U1; &mut rng);
+        // one contracted index in each tensor
+        check_inner!((Up, Lo), Up; U1, U2; &mut rng);
+        check_inner!((Lo, Lo), (Up, Lo); U0, U2; &mut rng);
+        check_inner!((Up, (Lo, Lo)), Up; U2, U3; &mut rng);
+        // both contracted indices in the second tensor
+        check_inner!(Up, (Up, Lo); U1, U2; &mut rng);
+        check_inner!(Lo, (Up, (Lo, Lo)); U1, U2; &mut rng);
+    }
+}
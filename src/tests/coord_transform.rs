@@ -1,6 +1,9 @@
-use crate::coordinates::{ConversionTo, CoordinateSystem, Point};
-use crate::tensors::Vector;
-use crate::typenum::consts::U3;
+use crate::coordinates::{ConversionTo, CoordinateSystem, DiffMethod, Point};
+use crate::tensors::{
+    ContravariantIndex, Covector, Density, Matrix, Scalar, Tensor, TensorDensity, TensorError,
+    TwoForm, Vector,
+};
+use crate::typenum::consts::{U2, U3};
 use generic_array::arr;
 
 struct Cartesian;
@@ -23,6 +26,15 @@ impl ConversionTo<Spherical> for Cartesian {
     }
 }
 
+impl ConversionTo<Cartesian> for Spherical {
+    fn convert_point(p: &Point<Spherical>) -> Point<Cartesian> {
+        let (r, theta, phi) = (p[0], p[1], p[2]);
+        Point::new(
+            arr![f64; r * theta.sin() * phi.cos(), r * theta.sin() * phi.sin(), r * theta.cos()],
+        )
+    }
+}
+
 #[test]
 fn test_vector_to_spherical() {
     let p = Point::new(arr![f64; 0.0, 1.0, 1.0]);
@@ -32,3 +44,396 @@ fn test_vector_to_spherical() {
     assert!((v2[1] + 0.5).abs() < 0.00001);
     assert_eq!(v2[2], 0.0);
 }
+
+#[test]
+fn test_covector_to_cartesian() {
+    // dr = (x dx + y dy + z dz) / r
+    let (theta, phi) = (1.0_f64, 0.5_f64);
+    let dr = Covector::<Spherical>::new(
+        Point::new(arr![f64; 2.0, theta, phi]),
+        arr![f64; 1.0, 0.0, 0.0],
+    );
+    let dr = dr.convert::<Cartesian>();
+    assert!((dr[0] - theta.sin() * phi.cos()).abs() < 1e-9);
+    assert!((dr[1] - theta.sin() * phi.sin()).abs() < 1e-9);
+    assert!((dr[2] - theta.cos()).abs() < 1e-9);
+}
+
+#[test]
+fn test_try_convert_singularity() {
+    let v = Vector::<Spherical>::new(
+        Point::new(arr![f64; 2.0, 1.0, 0.5]),
+        arr![f64; 1.0, 0.0, 0.0],
+    );
+    let v2 = v.try_convert::<Cartesian>().unwrap();
+    let expected = v.convert::<Cartesian>();
+    assert_eq!(v2.coords_array(), expected.coords_array());
+
+    // at θ = 0 the Jacobian isn't invertible
+    let v = Vector::<Spherical>::new(
+        Point::new(arr![f64; 2.0, 0.0, 0.5]),
+        arr![f64; 1.0, 0.0, 0.0],
+    );
+    assert_eq!(
+        v.try_convert::<Cartesian>().unwrap_err(),
+        TensorError::CoordinateSingularity {
+            point: vec![2.0, 0.0, 0.5]
+        }
+    );
+}
+
+#[cfg(feature = "std")]
+struct SphericalWithFallback;
+
+#[cfg(feature = "std")]
+impl CoordinateSystem for SphericalWithFallback {
+    type Dimension = U3;
+}
+
+#[cfg(feature = "std")]
+impl ConversionTo<Cartesian> for SphericalWithFallback {
+    fn convert_point(p: &Point<SphericalWithFallback>) -> Point<Cartesian> {
+        let (r, theta, phi) = (p[0], p[1], p[2]);
+        Point::new(
+            arr![f64; r * theta.sin() * phi.cos(), r * theta.sin() * phi.sin(), r * theta.cos()],
+        )
+    }
+
+    fn singular_tolerance() -> Option<f64> {
+        Some(1e-10)
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_convert_near_singularity() {
+    // dr is dz at the pole, and close to it
+    for &theta in &[0.0, 1e-14] {
+        let dr = Covector::<SphericalWithFallback>::new(
+            Point::new(arr![f64; 2.0, theta, 0.5]),
+            arr![f64; 1.0, 0.0, 0.0],
+        );
+        let dr = dr.convert::<Cartesian>();
+        assert!(dr[0].abs() < 1e-9 && dr[1].abs() < 1e-9 && (dr[2] - 1.0).abs() < 1e-9);
+    }
+
+    // away from the poles, the pseudo-inverse is the inverse
+    let p = Point::new(arr![f64; 2.0, 1.0, 0.5]);
+    let w = Covector::<SphericalWithFallback>::new(p, arr![f64; 1.0, 2.0, 3.0]);
+    let expected = Covector::<Spherical>::new(
+        Point::new(arr![f64; 2.0, 1.0, 0.5]),
+        arr![f64; 1.0, 2.0, 3.0],
+    )
+    .convert::<Cartesian>();
+    let w = w.convert::<Cartesian>();
+    for i in 0..3 {
+        assert!((w[i] - expected[i]).abs() < 1e-9);
+    }
+}
+
+#[cfg(feature = "std")]
+struct Cartesian4;
+
+#[cfg(feature = "std")]
+impl CoordinateSystem for Cartesian4 {
+    type Dimension = crate::typenum::consts::U4;
+}
+
+// Hyperspherical coordinates (r, χ, θ, φ), singular at χ = 0, where the Jacobian has rank 2
+#[cfg(feature = "std")]
+struct Hyperspherical;
+
+#[cfg(feature = "std")]
+impl CoordinateSystem for Hyperspherical {
+    type Dimension = crate::typenum::consts::U4;
+}
+
+#[cfg(feature = "std")]
+impl ConversionTo<Cartesian4> for Hyperspherical {
+    fn convert_point(p: &Point<Hyperspherical>) -> Point<Cartesian4> {
+        let (r, chi, theta, phi) = (p[0], p[1], p[2], p[3]);
+        Point::new(arr![f64;
+            r * chi.cos(),
+            r * chi.sin() * theta.cos(),
+            r * chi.sin() * theta.sin() * phi.cos(),
+            r * chi.sin() * theta.sin() * phi.sin()
+        ])
+    }
+
+    fn singular_tolerance() -> Option<f64> {
+        Some(1e-10)
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_convert_near_singularity_4d() {
+    // at the pole, dr is dx^0, and dχ is the covector along ∂_χ = r (0, cos θ, sin θ cos φ,
+    // sin θ sin φ) divided by r²
+    let (theta, phi) = (0.5f64, 1.0f64);
+    let p = Point::new(arr![f64; 2.0, 0.0, theta, phi]);
+    let dr = Covector::<Hyperspherical>::new(p, arr![f64; 1.0, 0.0, 0.0, 0.0]);
+    let dchi = Covector::<Hyperspherical>::new(p, arr![f64; 0.0, 1.0, 0.0, 0.0]);
+    let expected = [
+        [1.0, 0.0, 0.0, 0.0],
+        [
+            0.0,
+            theta.cos() / 2.0,
+            theta.sin() * phi.cos() / 2.0,
+            theta.sin() * phi.sin() / 2.0,
+        ],
+    ];
+    for (w, expected) in [dr, dchi].iter().zip(expected.iter()) {
+        let w = w.convert::<Cartesian4>();
+        for i in 0..4 {
+            assert!((w[i] - expected[i]).abs() < 1e-4);
+        }
+    }
+
+    // away from the pole, the pseudo-inverse is the inverse
+    let p = Point::new(arr![f64; 2.0, 1.0, 0.5, 1.0]);
+    let jacobian = <Hyperspherical as ConversionTo<Cartesian4>>::jacobian(&p);
+    let inverse = <Hyperspherical as ConversionTo<Cartesian4>>::inv_jacobian(&p);
+    let expected = jacobian.inverse().unwrap();
+    for i in 0..16 {
+        assert!((inverse[i] - expected[i]).abs() < 1e-9);
+    }
+}
+
+struct Cartesian2;
+
+impl CoordinateSystem for Cartesian2 {
+    type Dimension = U2;
+}
+
+fn polar_to_cartesian<T: CoordinateSystem>(p: &Point<T>) -> Point<Cartesian2> {
+    Point::new(arr![f64; p[0] * p[1].cos(), p[0] * p[1].sin()])
+}
+
+struct PolarForward;
+struct PolarCentral;
+struct PolarFivePoint;
+
+impl CoordinateSystem for PolarForward {
+    type Dimension = U2;
+
+    fn small(_: &Point<Self>) -> f64 {
+        0.1
+    }
+
+    fn diff_method() -> DiffMethod {
+        DiffMethod::Forward
+    }
+}
+
+impl CoordinateSystem for PolarCentral {
+    type Dimension = U2;
+
+    fn small(_: &Point<Self>) -> f64 {
+        0.1
+    }
+}
+
+impl CoordinateSystem for PolarFivePoint {
+    type Dimension = U2;
+
+    fn small(_: &Point<Self>) -> f64 {
+        0.1
+    }
+
+    fn diff_method() -> DiffMethod {
+        DiffMethod::FivePoint
+    }
+}
+
+impl ConversionTo<Cartesian2> for PolarForward {
+    fn convert_point(p: &Point<Self>) -> Point<Cartesian2> {
+        polar_to_cartesian(p)
+    }
+}
+
+impl ConversionTo<Cartesian2> for PolarCentral {
+    fn convert_point(p: &Point<Self>) -> Point<Cartesian2> {
+        polar_to_cartesian(p)
+    }
+}
+
+impl ConversionTo<Cartesian2> for PolarFivePoint {
+    fn convert_point(p: &Point<Self>) -> Point<Cartesian2> {
+        polar_to_cartesian(p)
+    }
+}
+
+// maximal difference between the numerical and the exact jacobian of the polar coordinates
+fn jacobian_error<T: ConversionTo<Cartesian2, Dimension = U2>>() -> f64 {
+    let (r, phi) = (2.0_f64, 0.7_f64);
+    let jacobian = T::jacobian(&Point::new(arr![f64; r, phi]));
+    let exact = [phi.cos(), -r * phi.sin(), phi.sin(), r * phi.cos()];
+    (0..4)
+        .map(|i| (jacobian[i] - exact[i]).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_diff_methods() {
+    let forward = jacobian_error::<PolarForward>();
+    let central = jacobian_error::<PolarCentral>();
+    let five_point = jacobian_error::<PolarFivePoint>();
+
+    assert!(forward > central);
+    assert!(central > five_point);
+    assert!(five_point < 1e-4);
+}
+
+#[test]
+fn test_polar_covector_to_cartesian() {
+    // dphi = (-y dx + x dy) / r^2 - the jacobian of the polar coordinates isn't symmetric,
+    // so contracting with the transposed inverse would mix up the components
+    let (r, phi) = (2.0_f64, 0.7_f64);
+    let dphi = Covector::<PolarFivePoint>::new(Point::new(arr![f64; r, phi]), arr![f64; 0.0, 1.0]);
+    let dphi = dphi.convert::<Cartesian2>();
+    assert!((dphi[0] + phi.sin() / r).abs() < 1e-4);
+    assert!((dphi[1] - phi.cos() / r).abs() < 1e-4);
+}
+
+#[test]
+fn test_jacobian_determinant() {
+    let (r, phi) = (2.0_f64, 0.7_f64);
+    let p = Point::new(arr![f64; r, phi]);
+    let det = <PolarFivePoint as ConversionTo<Cartesian2>>::jacobian_determinant(&p);
+    assert!((det - r).abs() < 1e-4);
+
+    let p = Point::new(arr![f64; r, phi]);
+    let det = <MacroPolarExact as ConversionTo<Cartesian2>>::jacobian_determinant(&p);
+    assert!((det - r).abs() < 1e-12);
+
+    // r^2 sin θ for the spherical coordinates
+    let (theta, phi) = (1.0_f64, 0.5_f64);
+    let p = Point::new(arr![f64; r, theta, phi]);
+    let det = <Spherical as ConversionTo<Cartesian>>::jacobian_determinant(&p);
+    assert!((det - r * r * theta.sin()).abs() < 1e-3);
+}
+
+crate::coordinate_system!(Polar2, U2);
+
+crate::conversion!(Cartesian2 => Polar2, |p: &Point<Cartesian2>| {
+    Point::new(arr![f64; p[0].hypot(p[1]), p[1].atan2(p[0])])
+});
+crate::conversion!(Polar2 => Cartesian2, |p: &Point<Polar2>| polar_to_cartesian(p));
+
+#[test]
+fn test_density_convert() {
+    // a uniform density in the plane picks up the factor r in polar coordinates
+    let p = Point::<Cartesian2>::new(arr![f64; 1.5, 2.0]);
+    let density = Density::new(p, 3.0).convert::<Polar2>();
+    assert!((density.get_point()[0] - 2.5).abs() < 1e-12);
+    assert!((density.value() - 3.0 * 2.5).abs() < 1e-4);
+
+    // converting back recovers the original value
+    let p = Point::<Polar2>::new(arr![f64; 2.5, 0.3]);
+    let density = Density::new(p, 7.5).convert::<Cartesian2>();
+    assert!((density.value() - 7.5 / 2.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_tensor_density_weights() {
+    let p = Point::<Cartesian2>::new(arr![f64; 1.5, 2.0]);
+
+    // weight 0 converts like an ordinary tensor
+    let v = Vector::new(p, arr![f64; 1.0, -2.0]);
+    let converted = TensorDensity::new(v, 0).convert::<Polar2>();
+    assert_eq!(converted.weight(), 0);
+    assert_eq!(
+        converted.tensor().coords_array(),
+        v.convert::<Polar2>().coords_array()
+    );
+
+    // a scalar of weight 1 converts like a density
+    let scalar = TensorDensity::new(Scalar::new(p, arr![f64; 3.0]), 1).convert::<Polar2>();
+    let density = Density::new(p, 3.0).convert::<Polar2>();
+    assert!((scalar.tensor()[0] - density.value()).abs() < 1e-12);
+    assert!((scalar.tensor()[0] - 3.0 * 2.5).abs() < 1e-4);
+
+    // the Levi-Civita symbols keep their components
+    let epsilon = TwoForm::new(p, arr![f64; 0.0, 1.0, -1.0, 0.0]);
+    let converted = TensorDensity::new(epsilon, -1).convert::<Polar2>();
+    let expected = [0.0, 1.0, -1.0, 0.0];
+    for (x, y) in converted
+        .tensor()
+        .coords_array()
+        .iter()
+        .zip(expected.iter())
+    {
+        assert!((x - y).abs() < 1e-4);
+    }
+    let epsilon = Tensor::<Cartesian2, (ContravariantIndex, ContravariantIndex)>::new(
+        p,
+        arr![f64; 0.0, 1.0, -1.0, 0.0],
+    );
+    let converted = TensorDensity::new(epsilon, 1).convert::<Polar2>();
+    for (x, y) in converted
+        .tensor()
+        .coords_array()
+        .iter()
+        .zip(expected.iter())
+    {
+        assert!((x - y).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_pushforward() {
+    // the rotation field ∂_φ in polar coordinates
+    let field = |p: Point<Polar2>| Vector::new(p, arr![f64; 0.0, 1.0]);
+    for &(r, phi) in &[(1.0, 0.3), (2.5, -1.2), (0.5, 2.0)] {
+        let v = field(Point::new(arr![f64; r, phi]));
+        let pushed = v.pushforward::<Cartesian2>();
+        let converted = v.convert::<Cartesian2>();
+        assert_eq!(pushed.get_point(), converted.get_point());
+        assert_eq!(pushed.coords_array(), converted.coords_array());
+    }
+
+    let p = Point::<Polar2>::new(arr![f64; 2.0, 0.7]);
+    let t = Tensor::<Polar2, (ContravariantIndex, ContravariantIndex)>::new(
+        p,
+        arr![f64; 1.0, 2.0, -0.5, 3.0],
+    );
+    let pushed = t.pushforward::<Cartesian2>();
+    let converted = t.convert::<Cartesian2>();
+    for (x, y) in pushed.coords_array().iter().zip(converted.coords_array()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+
+    // at the origin the Jacobian isn't invertible, but ∂_r still maps to a unit vector
+    let v = Vector::<Polar2>::new(Point::new(arr![f64; 0.0, 0.0]), arr![f64; 1.0, 0.0]);
+    let pushed = v.pushforward::<Cartesian2>();
+    assert!((pushed[0] - 1.0).abs() < 1e-4 && pushed[1].abs() < 1e-4);
+}
+
+crate::coordinate_system!(MacroPolar, U2);
+crate::coordinate_system!(MacroPolarExact, U2);
+
+crate::conversion!(MacroPolar => Cartesian2, |p: &Point<MacroPolar>| polar_to_cartesian(p));
+crate::conversion!(
+    MacroPolarExact => Cartesian2,
+    |p: &Point<MacroPolarExact>| polar_to_cartesian(p),
+    jacobian = |p: &Point<MacroPolarExact>| {
+        let (r, phi) = (p[0], p[1]);
+        Matrix::new(
+            polar_to_cartesian(p),
+            arr![f64; phi.cos(), -r * phi.sin(), phi.sin(), r * phi.cos()],
+        )
+    }
+);
+
+#[test]
+fn test_conversion_macro() {
+    // ∂_φ at (2, π/2) is (-2, 0)
+    let p = Point::new(arr![f64; 2.0, core::f64::consts::FRAC_PI_2]);
+    let v = Vector::<MacroPolar>::new(p, arr![f64; 0.0, 1.0]).convert::<Cartesian2>();
+    assert!((v[0] + 2.0).abs() < 1e-4 && v[1].abs() < 1e-4);
+
+    let p = Point::new(arr![f64; 2.0, core::f64::consts::FRAC_PI_2]);
+    let v = Vector::<MacroPolarExact>::new(p, arr![f64; 0.0, 1.0]).convert::<Cartesian2>();
+    assert!((v[0] + 2.0).abs() < 1e-15 && v[1].abs() < 1e-15);
+}
@@ -0,0 +1,157 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::inner;
+use crate::tensors::{
+    contraction_cost, einsum, einsum_single, path_cost, plan_contraction, ContravariantIndex as Up,
+    CovariantIndex as Lo, Tensor, TensorError, Vector, MAX_PLANNED_OPERANDS,
+};
+use crate::typenum::consts::{U1, U2, U3};
+use generic_array::GenericArray;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+fn random_matrix<R: Rng>(rng: &mut R, p: Point<Test3>) -> Tensor<Test3, (Up, Lo)> {
+    let coords: Vec<f64> = (0..9).map(|_| rng.gen_range(-1.0, 1.0)).collect();
+    Tensor::<Test3, (Up, Lo)>::from_slice(p, &coords)
+}
+
+#[test]
+fn test_contraction_order_costs() {
+    // a covector times two matrices: contracting from the left only ever involves two
+    // indices at a time, while multiplying the matrices first involves three
+    let expr = "i,ij,jk->k";
+    let left_first = path_cost(expr, 4, &[(0, 1), (0, 1)]).unwrap();
+    let right_first = path_cost(expr, 4, &[(1, 2), (0, 1)]).unwrap();
+    assert_eq!(left_first, 16 + 16);
+    assert_eq!(right_first, 64 + 16);
+
+    let plan = plan_contraction(expr, 4).unwrap();
+    assert_eq!(plan.cost, left_first);
+    assert_eq!(plan.steps, vec![(0, 1), (0, 1)]);
+
+    // evaluating everything at once loops over all three indices for both products
+    assert_eq!(contraction_cost(expr, 4).unwrap(), 2 * 64);
+    assert_eq!(contraction_cost("ii->", 4).unwrap(), 4);
+    assert_eq!(path_cost("ii->", 4, &[]).unwrap(), 4);
+}
+
+#[test]
+fn test_invalid_subscripts() {
+    let invalid = |result: Result<usize, TensorError>| {
+        matches!(result, Err(TensorError::InvalidSubscripts { .. }))
+    };
+    assert!(invalid(contraction_cost("ij,jk", 3)));
+    assert!(invalid(contraction_cost("ij,jk->iq", 3)));
+    assert!(invalid(contraction_cost("ij,jk->ii", 3)));
+    assert!(invalid(contraction_cost("i1,jk->i", 3)));
+    assert!(invalid(path_cost("ij,jk->ik", 3, &[(0, 0)])));
+    assert!(invalid(path_cost("ij,jk,kl->il", 3, &[(0, 1)])));
+
+    // only chains of matrix products short enough can be planned
+    let chain = |n: usize| {
+        let labels: Vec<char> = ('a'..='z').take(n + 1).collect();
+        let operands: Vec<String> = labels.windows(2).map(|w| w.iter().collect()).collect();
+        format!("{}->{}{}", operands.join(","), labels[0], labels[n])
+    };
+    assert!(plan_contraction(&chain(MAX_PLANNED_OPERANDS), 3).is_ok());
+    assert!(matches!(
+        plan_contraction(&chain(MAX_PLANNED_OPERANDS + 1), 3),
+        Err(TensorError::InvalidSubscripts { .. })
+    ));
+}
+
+#[test]
+fn test_contraction_cost_saturates() {
+    // 26 indices with 1000 values each overflow a usize
+    let expr = "abcdefghijklm,nopqrstuvwxyz->";
+    assert_eq!(contraction_cost(expr, 1000).unwrap(), usize::MAX);
+    assert_eq!(path_cost(expr, 1000, &[(0, 1)]).unwrap(), usize::MAX);
+    assert_eq!(plan_contraction(expr, 1000).unwrap().cost, usize::MAX);
+}
+
+#[test]
+fn test_einsum_matrix_product() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let p = Point::new(GenericArray::default());
+    let a = random_matrix(&mut rng, p);
+    let b = random_matrix(&mut rng, p);
+
+    let product = einsum::<Test3, (Up, Lo), (Up, Lo), (Up, Lo)>("ij,jk->ik", &a, &b).unwrap();
+    let expected = inner!(Tensor<Test3, (Up, Lo)>, Tensor<Test3, (Up, Lo)>; U1, U2; a, b);
+    assert!(product.components_eq(&expected, 1e-12));
+
+    // the order of the output indices is free, as long as the variances agree
+    let reversed = einsum::<Test3, (Up, Lo), (Up, Lo), (Lo, Up)>("ij,jk->ki", &a, &b).unwrap();
+    for i in 0..3 {
+        for k in 0..3 {
+            assert!((reversed[&[k, i][..]] - expected[&[i, k][..]]).abs() < 1e-12);
+        }
+    }
+
+    let v = Vector::<Test3>::from_slice(p, &[1.0, -2.0, 0.5]);
+    let av = einsum::<Test3, (Up, Lo), Up, Up>("ij,j->i", &a, &v).unwrap();
+    let expected = inner!(Tensor<Test3, (Up, Lo)>, Vector<Test3>; U1, U2; a, v);
+    for (x, y) in av.coords_array().iter().zip(expected.coords_array()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_einsum_trace() {
+    let mut rng = StdRng::seed_from_u64(12);
+    let p = Point::new(GenericArray::default());
+    let a = random_matrix(&mut rng, p);
+    let b = random_matrix(&mut rng, p);
+
+    let trace = einsum_single::<Test3, (Up, Lo), ()>("ii->", &a).unwrap();
+    assert!((*trace - a.trace_scalar()).abs() < 1e-12);
+
+    let trace = einsum::<Test3, (Up, Lo), (Up, Lo), ()>("ij,ji->", &a, &b).unwrap();
+    assert!((*trace - a.trace_of_product(&b)).abs() < 1e-12);
+}
+
+#[test]
+fn test_einsum_validation() {
+    let p = Point::new(GenericArray::default());
+    let a = Tensor::<Test3, (Up, Lo)>::unit(p);
+    let g = Tensor::<Test3, (Lo, Lo)>::unit(p);
+    let invalid = |result: Result<Tensor<Test3, (Up, Lo)>, TensorError>| {
+        matches!(result, Err(TensorError::InvalidSubscripts { .. }))
+    };
+    // wrong ranks of the operands or of the result
+    assert!(invalid(einsum::<Test3, (Up, Lo), (Up, Lo), (Up, Lo)>(
+        "ijk,jk->ik",
+        &a,
+        &a
+    )));
+    assert!(invalid(einsum::<Test3, (Up, Lo), (Up, Lo), (Up, Lo)>(
+        "ij,jk->i", &a, &a
+    )));
+    // a summed index with the same variance in both operands
+    assert!(invalid(einsum::<Test3, (Up, Lo), (Up, Lo), (Up, Lo)>(
+        "ij,kj->ik",
+        &a,
+        &a
+    )));
+    // an output index with the wrong variance
+    assert!(invalid(einsum::<Test3, (Up, Lo), (Up, Lo), (Up, Lo)>(
+        "ij,jk->ki",
+        &a,
+        &a
+    )));
+    // an index which appears three times
+    assert!(invalid(einsum::<Test3, (Up, Lo), (Lo, Lo), (Up, Lo)>(
+        "ij,jj->ij",
+        &a,
+        &g
+    )));
+
+    let q = Point::new(GenericArray::clone_from_slice(&[1.0, 0.0, 0.0]));
+    let b = Tensor::<Test3, (Up, Lo)>::unit(q);
+    let result = einsum::<Test3, (Up, Lo), (Up, Lo), (Up, Lo)>("ij,jk->ik", &a, &b);
+    assert!(matches!(result, Err(TensorError::PointMismatch { .. })));
+}
@@ -0,0 +1,211 @@
+use crate::coordinates::{ConversionTo, CoordinateSystem, Point};
+#[cfg(feature = "ndarray")]
+use crate::field::sample_field_to_ndarray;
+use crate::field::{
+    convert_field, covariant_hessian, directional_derivative, gradient, hessian, is_killing,
+    lie_derivative, sample_field, Field, TensorField,
+};
+use crate::metric::MetricSystem;
+use crate::systems::S2;
+use crate::tensors::{ContravariantIndex, Scalar, TwoForm, Vector};
+use crate::typenum::consts::U2;
+use generic_array::arr;
+
+struct Plane;
+
+impl CoordinateSystem for Plane {
+    type Dimension = U2;
+}
+
+fn rotation(p: &Point<Plane>) -> Vector<Plane> {
+    Vector::<Plane>::new(*p, arr![f64; -p[1], p[0]])
+}
+
+#[test]
+fn test_scalar_times_vector_field() {
+    let constant = |p: &Point<Plane>| Scalar::<Plane>::new(*p, arr![f64; 3.0]);
+    let product = Field(constant) * Field(rotation);
+
+    let p = Point::new(arr![f64; 1.0, 2.0]);
+    let value = product.value_at(&p);
+    assert_eq!(value.to_vec(), vec![-6.0, 3.0]);
+    assert_eq!(value.get_point(), &p);
+}
+
+struct Polar;
+
+impl CoordinateSystem for Polar {
+    type Dimension = U2;
+}
+
+impl MetricSystem for Polar {
+    fn g(p: &Point<Polar>) -> TwoForm<Polar> {
+        TwoForm::<Polar>::new(*p, arr![f64; 1.0, 0.0, 0.0, p[0] * p[0]])
+    }
+}
+
+#[test]
+fn test_hessian() {
+    let f = |p: &Point<Plane>| Scalar::<Plane>::new(*p, arr![f64; p[0] * p[0] + p[1] * p[1]]);
+    let p = Point::new(arr![f64; 1.0, -2.0]);
+    let result = hessian(&f, &p);
+    let expected = [2.0, 0.0, 0.0, 2.0];
+    for (x, y) in result.coords_array().iter().zip(expected.iter()) {
+        assert!((x - y).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_covariant_hessian() {
+    // x^2 + y^2 = r^2, whose covariant Hessian is twice the metric
+    let f = |p: &Point<Polar>| Scalar::<Polar>::new(*p, arr![f64; p[0] * p[0]]);
+    let p = Point::new(arr![f64; 2.0, 0.5]);
+    let result = covariant_hessian(&f, &p);
+    let expected = [2.0, 0.0, 0.0, 8.0];
+    for (x, y) in result.coords_array().iter().zip(expected.iter()) {
+        assert!((x - y).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_directional_derivative() {
+    let f = |p: &Point<Plane>| Scalar::<Plane>::new(*p, arr![f64; 3.0 * p[0] - 2.0 * p[1] + 1.0]);
+    let p = Point::new(arr![f64; 0.5, 1.5]);
+    let v = Vector::<Plane>::new(p, arr![f64; 2.0, 5.0]);
+
+    let grad = gradient(&f, &p);
+    let expected = grad[0] * v[0] + grad[1] * v[1];
+    assert!((*directional_derivative(&f, &v) - expected).abs() < 1e-12);
+    assert!((expected + 4.0).abs() < 1e-12);
+
+    // the derivative of the rotation field along any vector is the rotated vector
+    let result = directional_derivative(&rotation, &v);
+    assert!((result[0] + 5.0).abs() < 1e-12);
+    assert!((result[1] - 2.0).abs() < 1e-12);
+}
+
+// the Killing vector field generating rotations of the sphere about the x axis
+fn x_rotation(p: &Point<S2>) -> Vector<S2> {
+    let (theta, phi) = (p[0], p[1]);
+    Vector::<S2>::new(*p, arr![f64; -phi.sin(), -phi.cos() / theta.tan()])
+}
+
+#[test]
+fn test_lie_derivative() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    let result = lie_derivative(&S2::g, &x_rotation, &p);
+    assert!(result.coords_array().iter().all(|x| x.abs() < 1e-6));
+
+    // the Lie derivative of a vector field along itself vanishes
+    let p = Point::new(arr![f64; 1.0, 2.0]);
+    let result = lie_derivative(&rotation, &rotation, &p);
+    assert!(result.coords_array().iter().all(|x| x.abs() < 1e-12));
+
+    // the Lie derivative of a scalar field is the directional derivative
+    let f = |p: &Point<Plane>| Scalar::<Plane>::new(*p, arr![f64; p[0] * p[0]]);
+    let result = lie_derivative(&f, &rotation, &p);
+    assert!((*result + 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_is_killing() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    let z_rotation = |p: &Point<S2>| Vector::<S2>::new(*p, arr![f64; 0.0, 1.0]);
+    assert!(is_killing(&z_rotation, &S2::g, &p, 1e-6));
+    assert!(is_killing(&x_rotation, &S2::g, &p, 1e-6));
+
+    let polar = |p: &Point<S2>| Vector::<S2>::new(*p, arr![f64; 1.0, 0.0]);
+    assert!(!is_killing(&polar, &S2::g, &p, 1e-6));
+}
+
+#[test]
+fn test_sample_field() {
+    let f = |p: &Point<Plane>| Scalar::<Plane>::new(*p, arr![f64; p[0] * p[0] + 10.0 * p[1]]);
+    let samples = sample_field(&f, &[(0.0, 2.0), (-1.0, 1.0)], &[3, 5], 0);
+
+    assert_eq!(samples.len(), 15);
+    // the corners: (0, -1), (0, 1), (2, -1), (2, 1)
+    assert_eq!(samples[0], -10.0);
+    assert_eq!(samples[4], 10.0);
+    assert_eq!(samples[10], -6.0);
+    assert_eq!(samples[14], 14.0);
+    // (1, 0.5)
+    assert_eq!(samples[8], 6.0);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_sample_field_to_ndarray() {
+    let f = |p: &Point<Plane>| Scalar::<Plane>::new(*p, arr![f64; p[0] * p[0] + 10.0 * p[1]]);
+    let samples = sample_field_to_ndarray(&f, &[(0.0, 2.0), (-1.0, 1.0)], &[3, 5], 0);
+
+    assert_eq!(samples.shape(), &[3, 5]);
+    assert_eq!(samples[[0, 0]], -10.0);
+    assert_eq!(samples[[2, 4]], 14.0);
+    assert_eq!(samples[[1, 3]], 6.0);
+}
+
+impl ConversionTo<Plane> for Polar {
+    fn convert_point(p: &Point<Polar>) -> Point<Plane> {
+        Point::new(arr![f64; p[0] * p[1].cos(), p[0] * p[1].sin()])
+    }
+}
+
+impl ConversionTo<Polar> for Plane {
+    fn convert_point(p: &Point<Plane>) -> Point<Polar> {
+        Point::new(arr![f64; p[0].hypot(p[1]), p[1].atan2(p[0])])
+    }
+}
+
+#[test]
+fn test_convert_field() {
+    // the constant field ∂_r is the unit radial field in the plane
+    let radial = |p: &Point<Polar>| Vector::<Polar>::new(*p, arr![f64; 1.0, 0.0]);
+    let converted = convert_field::<Polar, Plane, ContravariantIndex, _>(radial);
+
+    for &(x, y) in &[(1.0, 0.0), (3.0, 4.0), (-1.0, 2.0)] {
+        let p = Point::new(arr![f64; x, y]);
+        let value = converted.value_at(&p);
+        let r = f64::hypot(x, y);
+        assert_eq!(value.get_point(), &p);
+        assert!((value[0] - x / r).abs() < 1e-6 && (value[1] - y / r).abs() < 1e-6);
+    }
+}
+
+// polar coordinates with differentiation steps proportional to the radius
+struct ScaledPolar;
+
+impl CoordinateSystem for ScaledPolar {
+    type Dimension = U2;
+
+    fn characteristic_scale(p: &Point<Self>, axis: usize) -> f64 {
+        if axis == 0 {
+            p[0]
+        } else {
+            1.0
+        }
+    }
+}
+
+#[test]
+fn test_characteristic_scale() {
+    // ln r + sin φ, whose radial derivative varies quickly near the origin
+    let f = |p: &Point<Polar>| Scalar::<Polar>::new(*p, arr![f64; p[0].ln() + p[1].sin()]);
+    let f_scaled =
+        |p: &Point<ScaledPolar>| Scalar::<ScaledPolar>::new(*p, arr![f64; p[0].ln() + p[1].sin()]);
+    let (r, phi): (f64, f64) = (0.02, 0.5);
+    let expected = [1.0 / r, phi.cos()];
+
+    let plain = gradient(&f, &Point::new(arr![f64; r, phi]));
+    let scaled = gradient(&f_scaled, &Point::new(arr![f64; r, phi]));
+    assert_eq!(ScaledPolar::diff_step(scaled.get_point(), 0), 0.01 * r);
+    assert_eq!(ScaledPolar::diff_step(scaled.get_point(), 1), 0.01);
+
+    let plain_error = (plain[0] - expected[0]).abs();
+    let scaled_error = (scaled[0] - expected[0]).abs();
+    assert!(scaled_error < 1e-3 * expected[0]);
+    assert!(scaled_error < 1e-3 * plain_error);
+    // the angular derivative is unaffected
+    assert_eq!(plain[1], scaled[1]);
+    assert!((scaled[1] - expected[1]).abs() < 1e-4);
+}
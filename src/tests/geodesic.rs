@@ -0,0 +1,67 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::geodesic::{integrate_geodesic, integrate_geodesic_deviation, parallel_transport};
+use crate::metric::MetricSystem;
+use crate::systems::S2;
+use crate::tensors::{Covector, TwoForm, Vector};
+use crate::typenum::consts::U2;
+use generic_array::arr;
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn test_equator_geodesic() {
+    let p = Point::<S2>::new(arr![f64; FRAC_PI_2, 0.0]);
+    let u = Vector::<S2>::new(p, arr![f64; 0.0, 1.0]);
+    let path = integrate_geodesic(&u, 0.01, 100);
+
+    let end = path.last().unwrap();
+    assert!((end.get_point()[0] - FRAC_PI_2).abs() < 1e-9);
+    assert!((end.get_point()[1] - 1.0).abs() < 1e-9);
+    assert!((end[1] - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_sphere_geodesic_deviation() {
+    // neighbouring great circles through the equator converge, their separation being
+    // proportional to cos τ
+    let p = Point::<S2>::new(arr![f64; FRAC_PI_2, 0.0]);
+    let u = Vector::<S2>::new(p, arr![f64; 0.0, 1.0]);
+    let xi = Vector::<S2>::new(p, arr![f64; 1e-3, 0.0]);
+    let path = integrate_geodesic_deviation(&u, &xi, 0.01, 100);
+
+    for (i, (_, separation)) in path.iter().enumerate().step_by(10) {
+        let tau = i as f64 * 0.01;
+        assert!((separation[0] - 1e-3 * tau.cos()).abs() < 1e-8);
+        assert!(separation[1].abs() < 1e-8);
+    }
+}
+
+struct Polar;
+
+impl CoordinateSystem for Polar {
+    type Dimension = U2;
+}
+
+impl MetricSystem for Polar {
+    fn g(point: &Point<Polar>) -> TwoForm<Polar> {
+        TwoForm::<Polar>::new(*point, arr![f64; 1.0, 0.0, 0.0, point[0] * point[0]])
+    }
+}
+
+#[test]
+fn test_parallel_transport_polar() {
+    // the unit vector and covector along the x axis, at (r, φ) = (1, 0)
+    let p = Point::<Polar>::new(arr![f64; 1.0, 0.0]);
+    let v = Vector::<Polar>::new(p, arr![f64; 1.0, 0.0]);
+    let w = Covector::<Polar>::new(p, arr![f64; 1.0, 0.0]);
+
+    // the plane is flat, so they should still point along the x axis at (r, φ) = (2, π/2)
+    let q = Point::<Polar>::new(arr![f64; 2.0, FRAC_PI_2]);
+    let v = parallel_transport(&v, &q, 100);
+    let w = parallel_transport(&w, &q, 100);
+
+    assert_eq!(*v.get_point(), q);
+    assert!(v[0].abs() < 1e-6);
+    assert!((v[1] + 0.5).abs() < 1e-6);
+    assert!(w[0].abs() < 1e-6);
+    assert!((w[1] + 2.0).abs() < 1e-6);
+}
@@ -0,0 +1,24 @@
+use crate::coordinates::Point;
+use crate::interpolation::{interpolate, Interpolation};
+use crate::systems::Cartesian;
+use crate::tensors::Matrix;
+use crate::typenum::consts::U2;
+use generic_array::arr;
+
+#[test]
+fn test_interpolation_flat() {
+    let p = Point::<Cartesian<U2>>::new(arr![f64; 0.0, 1.0]);
+    let q = Point::<Cartesian<U2>>::new(arr![f64; 2.0, -1.0]);
+    let a = Matrix::new(p, arr![f64; 1.0, 2.0, 3.0, 4.0]);
+    let b = Matrix::new(q, arr![f64; -1.0, 0.0, 1.0, 0.5]);
+
+    let naive = interpolate(&a, &b, 0.25, Interpolation::Naive);
+    let transported = interpolate(&a, &b, 0.25, Interpolation::Transported { steps: 10 });
+
+    assert_eq!(*naive.get_point(), Point::new(arr![f64; 0.5, 0.5]));
+    assert_eq!(naive.get_point(), transported.get_point());
+    assert_eq!(*naive.coords_array(), arr![f64; 0.5, 1.5, 2.5, 3.125]);
+    for i in 0..4 {
+        assert!((naive[i] - transported[i]).abs() < 1e-12);
+    }
+}
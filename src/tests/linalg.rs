@@ -0,0 +1,472 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::inner;
+use crate::tensors::{Matrix, TwoForm, Vector};
+use crate::typenum::consts::{U1, U2, U3, U4, U5};
+use crate::typenum::{Exp, Pow};
+use generic_array::{arr, ArrayLength, GenericArray};
+
+struct Test2;
+impl CoordinateSystem for Test2 {
+    type Dimension = U2;
+}
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+struct Test4;
+impl CoordinateSystem for Test4 {
+    type Dimension = U4;
+}
+
+struct Test5;
+impl CoordinateSystem for Test5 {
+    type Dimension = U5;
+}
+
+fn point() -> Point<Test3> {
+    Point::new(GenericArray::default())
+}
+
+fn assert_close(a: &[f64], b: &[f64], epsilon: f64) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((x - y).abs() < epsilon, "{:?} != {:?}", a, b);
+    }
+}
+
+fn assert_matrix_close(a: &Matrix<Test3>, b: &Matrix<Test3>, epsilon: f64) {
+    assert_eq!(a.get_point(), b.get_point());
+    assert_close(a.coords_array(), b.coords_array(), epsilon);
+}
+
+#[test]
+fn test_exp_zero() {
+    let zero = Matrix::<Test3>::zero(point());
+    let result = zero.matrix_exp();
+    assert_close(
+        result.coords_array(),
+        Matrix::<Test3>::unit(point()).coords_array(),
+        1e-15,
+    );
+}
+
+#[test]
+fn test_exp_nilpotent() {
+    // exp of a nilpotent matrix is a finite sum: I + N + N^2 / 2
+    let n = Matrix::<Test3>::from_slice(point(), &[0.0, 1.0, 2.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0]);
+    let result = n.matrix_exp();
+    assert_close(
+        result.coords_array(),
+        &[1.0, 1.0, 3.5, 0.0, 1.0, 3.0, 0.0, 0.0, 1.0],
+        1e-12,
+    );
+}
+
+#[test]
+fn test_exp_log_general() {
+    let a = Matrix::<Test3>::from_slice(point(), &[2.0, 0.5, 0.1, -0.3, 1.5, 0.2, 0.4, 0.0, 3.0]);
+    let log = a.matrix_log().unwrap();
+    let result = log.matrix_exp();
+    assert_close(result.coords_array(), a.coords_array(), 1e-9);
+}
+
+#[test]
+fn test_exp_log_symmetric() {
+    let a = Matrix::<Test3>::from_slice(point(), &[4.0, 1.0, 0.5, 1.0, 3.0, 0.2, 0.5, 0.2, 2.0]);
+    let log = a.matrix_log().unwrap();
+    let result = log.matrix_exp();
+    assert_close(result.coords_array(), a.coords_array(), 1e-12);
+
+    let indefinite =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 1.0]);
+    assert!(indefinite.matrix_log().is_none());
+}
+
+#[test]
+fn test_matrix_pow() {
+    let a = Matrix::<Test3>::from_slice(point(), &[2.0, 0.5, 0.1, -0.3, 1.5, 0.2, 0.4, 0.0, 3.0]);
+    let m = a.coords_array();
+
+    let mut square = [0.0; 9];
+    for i in 0..3 {
+        for j in 0..3 {
+            for k in 0..3 {
+                square[i * 3 + j] += m[i * 3 + k] * m[k * 3 + j];
+            }
+        }
+    }
+    let square = Matrix::<Test3>::from_slice(point(), &square);
+    assert_matrix_close(&a.matrix_pow(2).unwrap(), &square, 1e-12);
+
+    // negative powers are powers of the inverse linear map, which is again a (1, 1) tensor
+    let inverse: Matrix<Test3> = a.matrix_pow(-1).unwrap();
+    assert_close(
+        inverse.coords_array(),
+        a.inverse().unwrap().coords_array(),
+        1e-12,
+    );
+    let product: Matrix<Test3> = inner!(Matrix<Test3>, Matrix<Test3>; U1, U2; inverse, a);
+    assert_matrix_close(&product, &Matrix::<Test3>::unit(point()), 1e-12);
+
+    assert_matrix_close(
+        &a.matrix_pow(0).unwrap(),
+        &Matrix::<Test3>::unit(point()),
+        1e-15,
+    );
+
+    let singular =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0]);
+    assert!(singular.matrix_pow(-2).is_none());
+}
+
+#[test]
+fn test_matrix_sqrt() {
+    let a = Matrix::<Test3>::from_slice(point(), &[4.0, 1.0, 0.5, 1.0, 3.0, 0.2, 0.5, 0.2, 2.0]);
+    let sqrt = a.matrix_sqrt().unwrap();
+    assert_close(
+        sqrt.matrix_pow(2).unwrap().coords_array(),
+        a.coords_array(),
+        1e-12,
+    );
+
+    let not_symmetric =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    assert!(not_symmetric.matrix_sqrt().is_none());
+
+    let indefinite =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 2.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    assert!(indefinite.matrix_sqrt().is_none());
+}
+
+#[test]
+fn test_polar_decomposition() {
+    let a = Matrix::<Test3>::from_slice(point(), &[2.0, 0.5, 0.1, -0.3, 1.5, 0.2, 0.4, 0.0, 3.0]);
+    let (q, p) = a.polar_decomposition().unwrap();
+    let (q, p) = (q.coords_array(), p.coords_array());
+
+    let mut qtq = [0.0; 9];
+    let mut qp = [0.0; 9];
+    for i in 0..3 {
+        for j in 0..3 {
+            for k in 0..3 {
+                qtq[i * 3 + j] += q[k * 3 + i] * q[k * 3 + j];
+                qp[i * 3 + j] += q[i * 3 + k] * p[k * 3 + j];
+            }
+        }
+    }
+    assert_close(&qtq, Matrix::<Test3>::unit(point()).coords_array(), 1e-12);
+    assert_close(&qp, a.coords_array(), 1e-12);
+    assert!(Matrix::<Test3>::from_slice(point(), p)
+        .matrix_sqrt()
+        .is_some());
+
+    let singular =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0]);
+    assert!(singular.polar_decomposition().is_none());
+}
+
+#[test]
+fn test_qr_decomposition() {
+    let a = Matrix::<Test3>::from_slice(point(), &[2.0, 0.5, 0.1, -0.3, 1.5, 0.2, 0.4, 0.0, 3.0]);
+    let (q, r) = a.qr_decomposition();
+    let (q, r) = (q.coords_array(), r.coords_array());
+
+    let mut qtq = [0.0; 9];
+    let mut qr = [0.0; 9];
+    for i in 0..3 {
+        for j in 0..3 {
+            for k in 0..3 {
+                qtq[i * 3 + j] += q[k * 3 + i] * q[k * 3 + j];
+                qr[i * 3 + j] += q[i * 3 + k] * r[k * 3 + j];
+            }
+        }
+    }
+    assert_close(&qtq, Matrix::<Test3>::unit(point()).coords_array(), 1e-12);
+    assert_close(&qr, a.coords_array(), 1e-12);
+    for i in 0..3 {
+        for j in 0..i {
+            assert!(r[i * 3 + j].abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_rotation_and_reflection() {
+    fn assert_orthogonal(m: &Matrix<Test3>) {
+        let m = m.coords_array();
+        let mut mtm = [0.0; 9];
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    mtm[i * 3 + j] += m[k * 3 + i] * m[k * 3 + j];
+                }
+            }
+        }
+        assert_close(&mtm, Matrix::<Test3>::unit(point()).coords_array(), 1e-12);
+    }
+
+    let rotation = Matrix::<Test3>::rotation(point(), 2, 0, 0.7);
+    assert_orthogonal(&rotation);
+    assert!((rotation.determinant() - 1.0).abs() < 1e-12);
+
+    let reflection = Matrix::<Test3>::reflection(point(), 1);
+    assert_orthogonal(&reflection);
+    assert!((reflection.determinant() + 1.0).abs() < 1e-12);
+
+    let p = Point::<Test2>::new(GenericArray::default());
+    let quarter = Matrix::<Test2>::rotation(p, 0, 1, std::f64::consts::FRAC_PI_2);
+    let e0 = Vector::<Test2>::from_slice(p, &[1.0, 0.0]);
+    let e1 = inner!(_, Vector<Test2>; U1, U2; quarter, e0);
+    assert_close(e1.coords_array(), &[0.0, 1.0], 1e-15);
+}
+
+#[test]
+fn test_apply() {
+    let rotation = Matrix::<Test3>::rotation(point(), 0, 1, std::f64::consts::FRAC_PI_2);
+    let e0 = Vector::<Test3>::from_slice(point(), &[1.0, 0.0, 0.0]);
+    assert_close(
+        rotation.apply_to_vector(&e0).coords_array(),
+        &[0.0, 1.0, 0.0],
+        1e-15,
+    );
+
+    let p = Point::<Test3>::new(arr![f64; 1.0, 0.0, 2.0]);
+    let moved = rotation.apply_to_point(&p, &arr![f64; 0.5, 0.5, 0.5]);
+    assert_close(moved.coords_array(), &[0.5, 1.5, 2.5], 1e-15);
+}
+
+#[test]
+fn test_spectral_radius() {
+    // eigenvalues -4, 2 and 1, with the eigenvector (1, -1, 0) for -4
+    let a = Matrix::<Test3>::from_slice(point(), &[-1.0, 3.0, 0.0, 3.0, -1.0, 0.0, 0.0, 0.0, 1.0]);
+    let (radius, v) = a.spectral_radius(1000);
+    assert!((radius - 4.0).abs() < 1e-10);
+    let v = v.coords_array();
+    let s = 1.0 / 2.0f64.sqrt();
+    assert!((v[0].abs() - s).abs() < 1e-6 && (v[0] + v[1]).abs() < 1e-6 && v[2].abs() < 1e-6);
+}
+
+// Checks that the SVD of the matrix reconstructs it, with orthogonal U and V and sorted
+// non-negative singular values
+fn check_svd<T>(coords: &[f64])
+where
+    T: CoordinateSystem,
+    T::Dimension: Pow<U2>,
+    Exp<T::Dimension, U2>: ArrayLength<f64>,
+{
+    let n = T::dimension();
+    let a = Matrix::<T>::from_slice(Point::new(GenericArray::default()), coords);
+    let (u, sigma, vt) = a.svd();
+    let (u, vt) = (u.coords_array(), vt.coords_array());
+    assert!(sigma.windows(2).all(|s| s[0] >= s[1]) && sigma[n - 1] >= 0.0);
+
+    let mut usvt = vec![0.0; n * n];
+    let mut utu = vec![0.0; n * n];
+    let mut vvt = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                usvt[i * n + j] += u[i * n + k] * sigma[k] * vt[k * n + j];
+                utu[i * n + j] += u[k * n + i] * u[k * n + j];
+                vvt[i * n + j] += vt[i * n + k] * vt[j * n + k];
+            }
+        }
+    }
+    let unit = Matrix::<T>::unit(Point::new(GenericArray::default()));
+    assert_close(&usvt, coords, 1e-12);
+    assert_close(&utu, unit.coords_array(), 1e-12);
+    assert_close(&vvt, unit.coords_array(), 1e-12);
+}
+
+// Returns the coordinates of I - J/n, the projector onto the vectors with coordinates summing
+// up to 0, which has rank n - 1
+fn projector(n: usize) -> Vec<f64> {
+    (0..n * n)
+        .map(|k| if k / n == k % n { 1.0 } else { 0.0 } - 1.0 / n as f64)
+        .collect()
+}
+
+#[test]
+fn test_svd() {
+    check_svd::<Test3>(&[2.0, 0.5, 0.1, -0.3, 1.5, 0.2, 0.4, 0.0, 3.0]);
+    check_svd::<Test3>(&[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0]);
+    check_svd::<Test3>(&[0.0; 9]);
+
+    check_svd::<Test4>(&[
+        4.0, 1.0, -0.5, 0.2, 0.3, 3.0, 1.0, -1.0, -0.7, 0.1, 2.0, 0.5, 1.0, -2.0, 0.4, 5.0,
+    ]);
+    // rank 2: the last two rows are r1 + r2 and 2 r1 - r2
+    check_svd::<Test4>(&[
+        1.0, 2.0, 0.0, -1.0, 0.0, 1.0, 3.0, 1.0, 1.0, 3.0, 3.0, 0.0, 2.0, 3.0, -3.0, -3.0,
+    ]);
+    // two vanishing singular values, equal to each other, and two equal non-zero ones
+    check_svd::<Test4>(&[
+        3.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -3.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    ]);
+    check_svd::<Test4>(&[0.0; 16]);
+
+    // rank 1: the outer product of (1, -2, 0.5, 3, 1) and (2, 1, 0, -1, 0.5)
+    let (x, y) = ([1.0, -2.0, 0.5, 3.0, 1.0], [2.0, 1.0, 0.0, -1.0, 0.5]);
+    let outer: Vec<f64> = (0..25).map(|k| x[k / 5] * y[k % 5]).collect();
+    check_svd::<Test5>(&outer);
+    let outer = Matrix::<Test5>::from_slice(Point::new(GenericArray::default()), &outer);
+    assert_eq!(outer.numerical_rank(1e-12), 1);
+}
+
+#[test]
+fn test_svd_projectors() {
+    // the null vector (1, ..., 1) / sqrt(n) is equally far from all the basis vectors used to
+    // complete U
+    check_svd::<Test4>(&projector(4));
+    check_svd::<Test5>(&projector(5));
+
+    let p4 = Matrix::<Test4>::from_slice(Point::new(GenericArray::default()), &projector(4));
+    assert_eq!(p4.numerical_rank(1e-12), 3);
+    let p5 = Matrix::<Test5>::from_slice(Point::new(GenericArray::default()), &projector(5));
+    assert_eq!(p5.numerical_rank(1e-12), 4);
+}
+
+#[test]
+fn test_pseudo_inverse() {
+    let a = TwoForm::<Test3>::from_slice(point(), &[2.0, 0.5, 0.1, -0.3, 1.5, 0.2, 0.4, 0.0, 3.0]);
+    let pinv = a.pseudo_inverse(1e-12);
+    assert_close(
+        pinv.coords_array(),
+        a.inverse().unwrap().coords_array(),
+        1e-12,
+    );
+
+    // the pseudo-inverse of diag(2, 0, 4) is diag(1/2, 0, 1/4)
+    let singular =
+        TwoForm::<Test3>::from_slice(point(), &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 4.0]);
+    assert!(singular.inverse().is_none());
+    let pinv = singular.pseudo_inverse(1e-12);
+    assert_close(
+        pinv.coords_array(),
+        &[0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.25],
+        1e-15,
+    );
+
+    // A A⁺ A = A for a rank-deficient matrix
+    let singular =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0]);
+    let (a, p) = (singular.coords_array(), singular.pseudo_inverse(1e-12));
+    let p = p.coords_array();
+    let mut apa = [0.0; 9];
+    for i in 0..3 {
+        for j in 0..3 {
+            for k in 0..3 {
+                for l in 0..3 {
+                    apa[i * 3 + j] += a[i * 3 + k] * p[k * 3 + l] * a[l * 3 + j];
+                }
+            }
+        }
+    }
+    assert_close(&apa, a, 1e-12);
+}
+
+#[test]
+fn test_pseudo_inverse_rank_deficient() {
+    let point = Point::<Test4>::new(GenericArray::default());
+
+    // the projector is its own pseudo-inverse
+    let projector = Matrix::<Test4>::from_slice(point, &projector(4));
+    assert_close(
+        projector.pseudo_inverse(1e-12).coords_array(),
+        projector.coords_array(),
+        1e-12,
+    );
+
+    // the Penrose conditions for a matrix of rank 2
+    let a = [
+        1.0, 2.0, 0.0, -1.0, 0.0, 1.0, 3.0, 1.0, 1.0, 3.0, 3.0, 0.0, 2.0, 3.0, -3.0, -3.0,
+    ];
+    let pinv = Matrix::<Test4>::from_slice(point, &a).pseudo_inverse(1e-12);
+    let p = pinv.coords_array();
+    let product = |x: &[f64], y: &[f64]| {
+        let mut result = [0.0; 16];
+        for i in 0..4 {
+            for j in 0..4 {
+                for k in 0..4 {
+                    result[i * 4 + j] += x[i * 4 + k] * y[k * 4 + j];
+                }
+            }
+        }
+        result
+    };
+    let transpose = |x: &[f64]| -> Vec<f64> { (0..16).map(|k| x[k % 4 * 4 + k / 4]).collect() };
+    let (ap, pa) = (product(&a, p), product(p, &a));
+    assert_close(&product(&ap, &a), &a, 1e-12);
+    assert_close(&product(&pa, p), p, 1e-12);
+    assert_close(&ap, &transpose(&ap), 1e-12);
+    assert_close(&pa, &transpose(&pa), 1e-12);
+}
+
+#[test]
+fn test_numerical_rank() {
+    let a = Matrix::<Test3>::from_slice(point(), &[2.0, 0.5, 0.1, -0.3, 1.5, 0.2, 0.4, 0.0, 3.0]);
+    assert_eq!(a.numerical_rank(1e-12), 3);
+
+    let singular =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0]);
+    assert_eq!(singular.numerical_rank(1e-12), 2);
+
+    let rank_one =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, -1.0, -2.0, -3.0]);
+    assert_eq!(rank_one.numerical_rank(1e-12), 1);
+    assert_eq!(Matrix::<Test3>::zero(point()).numerical_rank(1e-12), 0);
+}
+
+#[test]
+fn test_solve_normal_equations() {
+    let a = Matrix::<Test3>::from_slice(point(), &[2.0, 0.5, 0.1, -0.3, 1.5, 0.2, 0.4, 0.0, 3.0]);
+    let b = Vector::<Test3>::from_slice(point(), &[1.0, -2.0, 0.5]);
+    let x = a.solve_normal_equations(&b).unwrap();
+
+    // compare with the solution using the inverse calculated from the LU decomposition
+    let inv = a.inverse().unwrap();
+    let expected: Vec<f64> = (0..3)
+        .map(|i| (0..3).map(|j| inv[i * 3 + j] * b[j]).sum())
+        .collect();
+    assert_close(x.coords_array(), &expected, 1e-12);
+
+    let singular =
+        Matrix::<Test3>::from_slice(point(), &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 0.0, 1.0]);
+    assert!(singular.solve_normal_equations(&b).is_none());
+}
+
+#[test]
+fn test_anticommutator() {
+    let p = Point::new(arr![f64; 0.0, 0.0]);
+    // the Pauli matrices σ_x and σ_z anticommute and square to the identity
+    let sx = Matrix::<Test2>::from_slice(p, &[0.0, 1.0, 1.0, 0.0]);
+    let sz = Matrix::<Test2>::from_slice(p, &[1.0, 0.0, 0.0, -1.0]);
+    assert_close(sx.anticommutator(&sz).coords_array(), &[0.0; 4], 1e-15);
+    assert_close(
+        sx.anticommutator(&sx).coords_array(),
+        &[2.0, 0.0, 0.0, 2.0],
+        1e-15,
+    );
+    assert_close(
+        sx.commutator(&sz).coords_array(),
+        &[0.0, -2.0, 2.0, 0.0],
+        1e-15,
+    );
+
+    let a = Matrix::<Test3>::from_slice(point(), &[1.0, 2.0, 0.0, -1.0, 3.0, 4.0, 2.0, 0.5, 1.0]);
+    let b = Matrix::<Test3>::from_slice(point(), &[0.0, 1.0, 5.0, 2.0, -2.0, 1.0, 3.0, 1.0, 0.0]);
+    let ab = a.anticommutator(&b);
+    assert_eq!(ab.coords_array(), b.anticommutator(&a).coords_array());
+    let c = a.commutator(&b) + b.commutator(&a);
+    assert_close(c.coords_array(), &[0.0; 9], 1e-15);
+
+    let unit = Matrix::<Test3>::unit(point());
+    let twice = a + a;
+    assert_close(
+        a.anticommutator(&unit).coords_array(),
+        twice.coords_array(),
+        1e-15,
+    );
+}
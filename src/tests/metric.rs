@@ -0,0 +1,281 @@
+use crate::coordinates::Embedding;
+use crate::coordinates::{CoordinateSystem, Point, SpatialAxes};
+use crate::metric::{adm_split, gaussian_curvature, induced_metric, MetricSystem};
+use crate::systems::{Cartesian, Mass, Minkowski, MostlyMinus, MostlyPlus, Schwarzschild, S2};
+use crate::tensors::{CovariantIndex, Covector, InnerProduct, InvTwoForm, Tensor, TwoForm};
+use crate::typenum::consts::{U1, U2, U3};
+use generic_array::{arr, GenericArray};
+
+#[test]
+fn test_perfect_fluid_at_rest() {
+    let p = Point::new(GenericArray::default());
+    // the velocity gets normalized, so its length doesn't matter
+    let u = Covector::<Minkowski>::new(p, arr![f64; -2.0, 0.0, 0.0, 0.0]);
+    let t = TwoForm::perfect_fluid(3.0, 0.5, &u);
+
+    for i in 0..4 {
+        for j in 0..4 {
+            let expected = match (i, j) {
+                (0, 0) => 3.0,
+                (i, j) if i == j => 0.5,
+                _ => 0.0,
+            };
+            assert!((t[&[i, j][..]] - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "timelike")]
+fn test_perfect_fluid_spacelike() {
+    let p = Point::new(GenericArray::default());
+    let u = Covector::<Minkowski>::new(p, arr![f64; 0.5, 1.0, 0.0, 0.0]);
+    TwoForm::perfect_fluid(3.0, 0.5, &u);
+}
+
+#[test]
+fn test_minkowski() {
+    let p = Point::new(arr![f64; 1.0, 2.0, -1.0, 0.5]);
+    assert_eq!(Minkowski::<MostlyPlus>::g(&p).determinant(), -1.0);
+    assert_eq!(
+        Minkowski::<MostlyMinus>::g(&Point::new(*p.coords_array())).determinant(),
+        -1.0
+    );
+
+    let riemann = Minkowski::<MostlyPlus>::riemann(&p);
+    assert!(riemann.coords_array().iter().all(|x| x.abs() < 1e-12));
+}
+
+#[test]
+fn test_spatial_metric() {
+    let p = Point::new(arr![f64; 1.0, 2.0, -1.0, 0.5]);
+    let spatial = Minkowski::<MostlyPlus>::g(&p).project::<SpatialAxes>();
+
+    assert_eq!(
+        *spatial.get_point().coords_array(),
+        arr![f64; 2.0, -1.0, 0.5]
+    );
+    assert_eq!(
+        *spatial.coords_array(),
+        arr![f64; 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+    );
+}
+
+#[test]
+fn test_adm_split() {
+    let p = Point::new(arr![f64; 1.0, 2.0, -1.0, 0.5]);
+    let adm = adm_split::<Minkowski>(&p).unwrap();
+
+    assert_eq!(adm.lapse, 1.0);
+    assert_eq!(*adm.shift.coords_array(), arr![f64; 0.0, 0.0, 0.0]);
+    assert_eq!(
+        *adm.spatial_metric.coords_array(),
+        arr![f64; 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+    );
+
+    let p = Point::new(arr![f64; 0.0, 10.0, 1.0, 0.5]);
+    let adm = adm_split::<Schwarzschild>(&p).unwrap();
+    assert!((adm.lapse - 0.8f64.sqrt()).abs() < 1e-12);
+
+    // with the (+,-,-,-) signature, the slices of constant t are not spacelike
+    assert!(
+        adm_split::<Minkowski<MostlyMinus>>(&Point::new(arr![f64; 0.0, 0.0, 0.0, 0.0])).is_none()
+    );
+}
+
+struct TwoMasses;
+
+impl Mass for TwoMasses {
+    const MASS: f64 = 2.0;
+}
+
+#[test]
+fn test_schwarzschild_vacuum() {
+    let p = Point::new(arr![f64; 0.0, 10.0, 1.0, 0.5]);
+
+    let ricci = Schwarzschild::<TwoMasses>::ricci(&p);
+    assert!(ricci.coords_array().iter().all(|x| x.abs() < 1e-6));
+
+    // the exact value is 48 M^2 / r^6
+    let kretschmann = Schwarzschild::<TwoMasses>::kretschmann(&p);
+    let expected = 48.0 * 4.0 / 1e6;
+    assert!((kretschmann - expected).abs() < 1e-3 * expected);
+}
+
+#[test]
+#[should_panic]
+fn test_schwarzschild_horizon() {
+    let p = Point::new(arr![f64; 0.0, 4.0, 1.0, 0.5]);
+    let _ = Schwarzschild::<TwoMasses>::g(&p);
+}
+
+#[test]
+fn test_sphere_curvature() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    // the Ricci scalar of a 2D surface is twice its Gaussian curvature
+    assert!((S2::ricci_scalar(&p) / 2.0 - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_sphere_riemann_nonzero() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    let riemann = S2::riemann(&p);
+
+    // all the components follow from R_{θφθφ} by the symmetries of the tensor
+    assert_eq!(riemann.count_nonzero(1e-6), 4);
+    let mask = riemann.mask(|x| x.abs() > 1e-6);
+    let nonzero: Vec<_> = (0..16).filter(|&i| mask[i]).collect();
+    // R^θ_{φθφ}, R^θ_{φφθ}, R^φ_{θθφ}, R^φ_{θφθ}
+    assert_eq!(nonzero, vec![5, 6, 9, 10]);
+}
+
+#[test]
+fn test_sphere_christoffel() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    let exact = S2::christoffel(&p);
+    let numerical = <InvTwoForm<S2> as InnerProduct<
+        Tensor<S2, (CovariantIndex, (CovariantIndex, CovariantIndex))>,
+        U1,
+        U2,
+    >>::inner_product(S2::inv_g(&p), S2::covariant_christoffel(&p));
+
+    for (x, y) in exact
+        .coords_array()
+        .iter()
+        .zip(numerical.coords_array().iter())
+    {
+        assert!((x - y).abs() < 1e-4);
+    }
+    assert!((exact[&[0, 1, 1][..]] + 1.0_f64.sin() * 1.0_f64.cos()).abs() < 1e-15);
+    assert!((exact[&[1, 0, 1][..]] - 1.0 / 1.0_f64.tan()).abs() < 1e-15);
+
+    // at the poles everything stays finite
+    let pole = Point::new(arr![f64; 0.0, 0.3]);
+    let gamma = S2::christoffel(&pole);
+    assert!(gamma.coords_array().iter().all(|x| x.is_finite()));
+    assert!(S2::inv_g(&pole)
+        .coords_array()
+        .iter()
+        .all(|x| x.is_finite()));
+}
+
+#[test]
+fn test_sphere_embedding() {
+    let p = Point::new(arr![f64; 1.0, 0.3]);
+    let q = S2::embed_point(&p);
+    let r2: f64 = q.coords_array().iter().map(|x| x * x).sum();
+    assert!((r2 - 1.0).abs() < 1e-15);
+
+    // the tangent vectors induce the metric of the sphere
+    let tangent = S2::tangent_vectors(&p);
+    let g = S2::g(&p);
+    for i in 0..2 {
+        for j in 0..2 {
+            let dot: f64 = (0..3).map(|k| tangent[i][k] * tangent[j][k]).sum();
+            assert!((dot - g[&[i, j][..]]).abs() < 1e-15);
+        }
+    }
+}
+
+struct FlatTorus;
+
+impl CoordinateSystem for FlatTorus {
+    type Dimension = U2;
+}
+
+impl MetricSystem for FlatTorus {
+    fn g(point: &Point<FlatTorus>) -> TwoForm<FlatTorus> {
+        TwoForm::<FlatTorus>::new(*point, arr![f64; 4.0, 0.0, 0.0, 1.0])
+    }
+}
+
+struct Polar;
+
+impl CoordinateSystem for Polar {
+    type Dimension = U2;
+}
+
+impl MetricSystem for Polar {
+    fn g(point: &Point<Polar>) -> TwoForm<Polar> {
+        TwoForm::<Polar>::new(*point, arr![f64; 1.0, 0.0, 0.0, point[0] * point[0]])
+    }
+}
+
+#[test]
+fn test_gaussian_curvature() {
+    let p = Point::<S2>::new(arr![f64; 1.0, 0.3]);
+    assert!((gaussian_curvature(&p) - 1.0).abs() < 1e-6);
+
+    let p = Point::<FlatTorus>::new(arr![f64; 0.5, 2.0]);
+    assert!(gaussian_curvature(&p).abs() < 1e-12);
+
+    let p = Point::<Polar>::new(arr![f64; 2.0, 0.3]);
+    assert!(gaussian_curvature(&p).abs() < 1e-9);
+}
+
+crate::coordinate_system!(
+    DeclaredPolar,
+    U2,
+    metric = |p: &Point<DeclaredPolar>| TwoForm::new(*p, arr![f64; 1.0, 0.0, 0.0, p[0] * p[0]])
+);
+
+#[test]
+fn test_coordinate_system_macro_metric() {
+    let p = Point::<DeclaredPolar>::new(arr![f64; 2.0, 0.5]);
+    assert_eq!(DeclaredPolar::g(&p).to_vec(), vec![1.0, 0.0, 0.0, 4.0]);
+    assert_eq!(DeclaredPolar::inv_g(&p).to_vec(), vec![1.0, 0.0, 0.0, 0.25]);
+}
+
+#[test]
+fn test_is_flat() {
+    // the numerical errors grow near the origin, where the Christoffel symbols vary quickly
+    for &(r, phi) in &[(2.0, 0.5), (0.7, -2.0), (5.0, 3.0)] {
+        let p = Point::<DeclaredPolar>::new(arr![f64; r, phi]);
+        assert!(DeclaredPolar::is_flat(&p, 1e-3));
+    }
+    assert!(Minkowski::<MostlyPlus>::is_flat(
+        &Point::new(GenericArray::default()),
+        1e-12
+    ));
+
+    let p = Point::new(arr![f64; 1.0, 0.5]);
+    assert!(!S2::is_flat(&p, 1e-3));
+    let p = Point::<Schwarzschild>::new(arr![f64; 0.0, 5.0, 1.0, 0.0]);
+    assert!(!Schwarzschild::is_flat(&p, 1e-3));
+}
+
+// the sphere of radius 2, with the tangent vectors calculated numerically
+struct Sphere2;
+
+impl CoordinateSystem for Sphere2 {
+    type Dimension = U2;
+}
+
+impl Embedding<Cartesian<U3>> for Sphere2 {
+    fn embed_point(p: &Point<Self>) -> Point<Cartesian<U3>> {
+        let (theta, phi) = (p[0], p[1]);
+        Point::new(arr![f64;
+            2.0 * theta.sin() * phi.cos(),
+            2.0 * theta.sin() * phi.sin(),
+            2.0 * theta.cos()
+        ])
+    }
+}
+
+#[test]
+fn test_induced_metric() {
+    let p = Point::new(arr![f64; 0.8, 2.0]);
+    let induced = induced_metric::<S2, Cartesian<U3>>(&p);
+    let exact = S2::g(&p);
+    for (x, y) in induced.coords_array().iter().zip(exact.coords_array()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+
+    let p = Point::new(arr![f64; 0.8, 2.0]);
+    let induced = induced_metric::<Sphere2, Cartesian<U3>>(&p);
+    let sin_theta = 0.8_f64.sin();
+    let expected = [4.0, 0.0, 0.0, 4.0 * sin_theta * sin_theta];
+    for (x, y) in induced.coords_array().iter().zip(expected.iter()) {
+        assert!((x - y).abs() < 1e-3);
+    }
+}
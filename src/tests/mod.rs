@@ -1,2 +1,28 @@
 mod basic;
+#[cfg(feature = "complex")]
+mod complex;
+#[cfg(feature = "std")]
+mod connection;
+mod contraction;
 mod coord_transform;
+mod einsum;
+#[cfg(feature = "std")]
+mod field;
+#[cfg(feature = "std")]
+mod geodesic;
+#[cfg(feature = "std")]
+mod interpolation;
+#[cfg(feature = "std")]
+mod linalg;
+#[cfg(feature = "std")]
+mod metric;
+#[cfg(feature = "plotters")]
+mod plot;
+mod properties;
+#[cfg(feature = "rand")]
+mod random;
+mod reference;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "std")]
+mod surface;
@@ -0,0 +1,34 @@
+use crate::coordinates::Point;
+use crate::plot::{draw_heatmap, draw_quiver};
+use crate::systems::Cartesian;
+use crate::tensors::{Scalar, Vector};
+use crate::typenum::consts::U2;
+use generic_array::arr;
+use plotters::prelude::*;
+
+type Plane = Cartesian<U2>;
+
+#[test]
+fn test_plot_radial_field() {
+    let length = |p: &Point<Plane>| Scalar::<Plane>::new(*p, arr![f64; p[0].hypot(p[1])]);
+    let radial = |p: &Point<Plane>| Vector::<Plane>::new(*p, arr![f64; p[0], p[1]]);
+    let bounds = [(-1.0, 1.0), (-1.0, 1.0)];
+
+    let mut buffer = vec![0; 100 * 100 * 3];
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        root.fill(&WHITE).unwrap();
+        let mut chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(-1.0..1.0, -1.0..1.0)
+            .unwrap();
+        draw_heatmap(&mut chart, &length, bounds, [10, 10]).unwrap();
+        draw_quiver(&mut chart, &radial, bounds, [5, 5], BLACK.into()).unwrap();
+        root.present().unwrap();
+    }
+
+    // the field is larger in the corners than near the origin
+    let pixel = |x: usize, y: usize| &buffer[(y * 100 + x) * 3..(y * 100 + x + 1) * 3];
+    assert_ne!(pixel(2, 2), [255, 255, 255]);
+    assert_ne!(pixel(47, 47), [255, 255, 255]);
+    assert_ne!(pixel(2, 2), pixel(47, 47));
+}
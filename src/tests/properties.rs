@@ -0,0 +1,88 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::tensors::{Matrix, TwoForm, Vector};
+use crate::typenum::consts::U3;
+use generic_array::GenericArray;
+use proptest::prelude::*;
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+const EPSILON: f64 = 1e-9;
+
+fn point() -> Point<Test3> {
+    Point::new(GenericArray::default())
+}
+
+fn vector() -> impl Strategy<Value = Vector<Test3>> {
+    prop::collection::vec(-10.0..10.0f64, 3).prop_map(|v| Vector::from_slice(point(), &v))
+}
+
+fn matrix() -> impl Strategy<Value = Matrix<Test3>> {
+    prop::collection::vec(-10.0..10.0f64, 9).prop_map(|v| Matrix::from_slice(point(), &v))
+}
+
+// Makes the matrix strictly diagonally dominant, which guarantees it to be invertible
+fn invertible_matrix() -> impl Strategy<Value = Matrix<Test3>> {
+    matrix().prop_map(|mut m| {
+        for i in 0..3 {
+            let row_sum: f64 = (0..3).map(|j| m[&[i, j][..]].abs()).sum();
+            m[&[i, i][..]] = row_sum + 1.0;
+        }
+        m
+    })
+}
+
+fn assert_close(a: &Vector<Test3>, b: &Vector<Test3>) {
+    for i in 0..3 {
+        assert!((a[i] - b[i]).abs() < EPSILON);
+    }
+}
+
+fn scale(mut v: Vector<Test3>, s: f64) -> Vector<Test3> {
+    v *= s;
+    v
+}
+
+proptest! {
+    #[test]
+    fn addition_is_commutative(a in vector(), b in vector()) {
+        assert_close(&(a + b), &(b + a));
+    }
+
+    #[test]
+    fn addition_is_associative(a in vector(), b in vector(), c in vector()) {
+        assert_close(&((a + b) + c), &(a + (b + c)));
+    }
+
+    #[test]
+    fn scalar_multiplication_is_distributive(
+        a in vector(),
+        b in vector(),
+        s in -10.0..10.0f64,
+        t in -10.0..10.0f64,
+    ) {
+        assert_close(&scale(a + b, s), &(scale(a, s) + scale(b, s)));
+        assert_close(&scale(a, s + t), &(scale(a, s) + scale(a, t)));
+    }
+
+    #[test]
+    fn transpose_is_an_involution(m in matrix()) {
+        let form = TwoForm::<Test3>::from_slice(point(), m.coords_array());
+        prop_assert_eq!(m.transpose().transpose().to_vec(), m.to_vec());
+        prop_assert_eq!(form.transpose().transpose().to_vec(), form.to_vec());
+    }
+
+    #[test]
+    fn inverse_times_matrix_is_identity(m in invertible_matrix()) {
+        let inv = m.inverse().unwrap();
+        for i in 0..3 {
+            for k in 0..3 {
+                let product: f64 = (0..3).map(|j| inv[&[i, j][..]] * m[&[j, k][..]]).sum();
+                let expected = if i == k { 1.0 } else { 0.0 };
+                prop_assert!((product - expected).abs() < EPSILON);
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::tensors::{Matrix, TwoForm, Vector};
+use crate::typenum::consts::U3;
+use generic_array::GenericArray;
+use rand::distributions::Uniform;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+#[test]
+fn test_random() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let p = Point::new(GenericArray::default());
+    let v = Vector::<Test3>::random(p, &mut rng, &Uniform::new(2.0, 3.0));
+    assert!(v.coords_array().iter().all(|x| (2.0..3.0).contains(x)));
+
+    let m = Matrix::<Test3>::random_symmetric(p, &mut rng, &Uniform::new(-1.0, 1.0));
+    assert!(m.equals_symmetrized(&m, 0.0));
+}
+
+#[test]
+fn test_random_spd() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let p = Point::new(GenericArray::default());
+
+    for _ in 0..10 {
+        let m = TwoForm::<Test3>::random_spd(p, &mut rng, &Uniform::new(-1.0, 1.0));
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(m[&[i, j][..]], m[&[j, i][..]]);
+            }
+        }
+        assert!(m.inverse().is_some());
+        assert!(m.determinant() > 0.0);
+    }
+}
@@ -0,0 +1,67 @@
+//! Naive, obviously correct implementations of tensor operations, iterating explicitly
+//! over multi-indices. They are used as oracles for the optimized implementations.
+
+/// Splits a flat array index into a multi-index
+pub fn multi_index(mut flat: usize, dim: usize, rank: usize) -> Vec<usize> {
+    let mut result = vec![0; rank];
+    for k in (0..rank).rev() {
+        result[k] = flat % dim;
+        flat /= dim;
+    }
+    result
+}
+
+/// Joins a multi-index into a flat array index
+pub fn flat_index(idx: &[usize], dim: usize) -> usize {
+    idx.iter().fold(0, |res, i| res * dim + i)
+}
+
+/// Contracts the indices `l` and `h` (l < h) of a tensor of rank `rank`
+pub fn reference_trace(coords: &[f64], dim: usize, rank: usize, l: usize, h: usize) -> Vec<f64> {
+    let result_len = dim.pow((rank - 2) as u32);
+    (0..result_len)
+        .map(|flat| {
+            let rest = multi_index(flat, dim, rank - 2);
+            (0..dim)
+                .map(|i| {
+                    let mut idx = rest.clone();
+                    idx.insert(l, i);
+                    idx.insert(h, i);
+                    coords[flat_index(&idx, dim)]
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// Calculates the outer product of tensors of ranks `rank1` and `rank2`
+pub fn reference_outer(
+    coords1: &[f64],
+    coords2: &[f64],
+    dim: usize,
+    rank1: usize,
+    rank2: usize,
+) -> Vec<f64> {
+    let result_len = dim.pow((rank1 + rank2) as u32);
+    (0..result_len)
+        .map(|flat| {
+            let idx = multi_index(flat, dim, rank1 + rank2);
+            coords1[flat_index(&idx[..rank1], dim)] * coords2[flat_index(&idx[rank1..], dim)]
+        })
+        .collect()
+}
+
+/// Calculates the inner product of tensors of ranks `rank1` and `rank2`, contracting
+/// the indices `l` and `h` (l < h) of their outer product
+pub fn reference_inner_product(
+    coords1: &[f64],
+    coords2: &[f64],
+    dim: usize,
+    rank1: usize,
+    rank2: usize,
+    l: usize,
+    h: usize,
+) -> Vec<f64> {
+    let outer = reference_outer(coords1, coords2, dim, rank1, rank2);
+    reference_trace(&outer, dim, rank1 + rank2, l, h)
+}
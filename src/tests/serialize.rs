@@ -0,0 +1,70 @@
+use crate::coordinates::{CoordinateSystem, Point};
+use crate::tensors::{ContravariantIndex, CovariantIndex, Covector, Tensor, Vector};
+use crate::typenum::consts::{U3, U4};
+use generic_array::arr;
+use serde_json::{json, Value};
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+struct Test4;
+impl CoordinateSystem for Test4 {
+    type Dimension = U4;
+}
+
+fn vector_data(dimension: usize, index_type: &str, coords: &[f64]) -> Value {
+    json!({
+        "system": "Test3",
+        "dimension": dimension,
+        "variance": [index_type],
+        "point": vec![0.0; coords.len()],
+        "coords": coords,
+    })
+}
+
+#[test]
+fn test_deserialize() {
+    let data = vector_data(3, "Contravariant", &[1.0, 2.0, 3.0]);
+    let vector: Vector<Test3> = serde_json::from_value(data).unwrap();
+    assert_eq!(vector.to_vec(), vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_round_trip() {
+    let p = Point::new(arr![f64; 1.5, -2.0, 0.25]);
+    let vector = Vector::<Test3>::new(p, arr![f64; 1.0, -2.0, 3.5]);
+    let data = serde_json::to_string(&vector).unwrap();
+    let result: Vector<Test3> = serde_json::from_str(&data).unwrap();
+    assert_eq!(result.get_point(), vector.get_point());
+    assert_eq!(result.coords_array(), vector.coords_array());
+
+    let coords: Vec<f64> = (0..9).map(|i| i as f64 * 0.5 - 1.0).collect();
+    let tensor = Tensor::<Test3, (ContravariantIndex, CovariantIndex)>::from_slice(p, &coords);
+    let data = serde_json::to_string(&tensor).unwrap();
+    let result: Tensor<Test3, (ContravariantIndex, CovariantIndex)> =
+        serde_json::from_str(&data).unwrap();
+    assert_eq!(result.get_point(), tensor.get_point());
+    assert_eq!(result.coords_array(), tensor.coords_array());
+
+    // the variance is serialized too
+    assert!(
+        serde_json::from_str::<Tensor<Test3, (CovariantIndex, ContravariantIndex)>>(&data).is_err()
+    );
+}
+
+#[test]
+fn test_deserialize_mismatch() {
+    let data = vector_data(3, "Contravariant", &[1.0, 2.0, 3.0]);
+    let error = serde_json::from_value::<Vector<Test4>>(data).unwrap_err();
+    assert!(error.to_string().contains("dimension mismatch"));
+
+    let data = vector_data(3, "Contravariant", &[1.0, 2.0, 3.0]);
+    assert!(serde_json::from_value::<Covector<Test3>>(data).is_err());
+}
+
+#[test]
+fn test_system_name() {
+    assert!(Test3::system_name().ends_with("Test3"));
+}
@@ -0,0 +1,43 @@
+use crate::coordinates::{CoordinateSystem, Embedding, Point};
+use crate::surface::{principal_curvatures, shape_operator};
+use crate::systems::{Cartesian, S2};
+use crate::typenum::consts::{U2, U3};
+use generic_array::arr;
+
+const RADIUS: f64 = 2.5;
+
+struct Sphere;
+
+impl CoordinateSystem for Sphere {
+    type Dimension = U2;
+}
+
+// uses the default, numerical tangent vectors
+impl Embedding<Cartesian<U3>> for Sphere {
+    fn embed_point(p: &Point<Sphere>) -> Point<Cartesian<U3>> {
+        let (theta, phi) = (p[0], p[1]);
+        Point::new(arr![f64;
+            RADIUS * theta.sin() * phi.cos(),
+            RADIUS * theta.sin() * phi.sin(),
+            RADIUS * theta.cos()
+        ])
+    }
+}
+
+#[test]
+fn test_sphere_principal_curvatures() {
+    // the normal points outwards, so the curvatures are negative
+    let p = Point::<Sphere>::new(arr![f64; 1.0, 0.3]);
+    let [k1, k2] = principal_curvatures(&p);
+    assert!((k1 + 1.0 / RADIUS).abs() < 1e-5);
+    assert!((k2 + 1.0 / RADIUS).abs() < 1e-5);
+
+    let p = Point::<S2>::new(arr![f64; 1.0, 0.3]);
+    let [k1, k2] = principal_curvatures(&p);
+    assert!((k1 + 1.0).abs() < 1e-5);
+    assert!((k2 + 1.0).abs() < 1e-5);
+
+    let shape = shape_operator(&p);
+    assert!((shape[&[0, 0][..]] + 1.0).abs() < 1e-5);
+    assert!(shape[&[0, 1][..]].abs() < 1e-5);
+}
@@ -0,0 +1,65 @@
+//! Tests checking that some operations don't allocate, using an allocator counting the
+//! allocations made by the current thread. It is an integration test, so that the counting
+//! allocator only replaces the global one in its own test binary.
+
+use diffgeom::coordinates::CoordinateSystem;
+use diffgeom::tensors::{ContravariantIndex, CovariantIndex, IndexType, Tensor, Variance};
+use diffgeom::typenum::consts::U3;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Returns the result of `f` and the number of allocations it made
+fn count_allocations<R, F: FnOnce() -> R>(f: F) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+#[test]
+fn test_variance_array_doesnt_allocate() {
+    type V = (ContravariantIndex, (CovariantIndex, ContravariantIndex));
+
+    let (array, allocations) = count_allocations(V::variance_array);
+    assert_eq!(allocations, 0);
+    assert_eq!(&*array, &V::variance()[..]);
+
+    let (array, allocations) = count_allocations(Tensor::<Test3, V>::variance_array);
+    assert_eq!(allocations, 0);
+    assert_eq!(
+        &*array,
+        &[
+            IndexType::Contravariant,
+            IndexType::Covariant,
+            IndexType::Contravariant
+        ]
+    );
+
+    // make sure that the allocations are actually counted
+    let (_, allocations) = count_allocations(V::variance);
+    assert!(allocations > 0);
+}
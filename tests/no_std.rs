@@ -0,0 +1,37 @@
+//! Exercises the core tensor algebra from a `no_std` crate. Run with
+//! `cargo test --no-default-features` to check it against the `no_std` build of the library.
+
+#![no_std]
+
+use diffgeom::coordinates::{CoordinateSystem, Point};
+use diffgeom::tensors::{Covector, Matrix, Vector};
+use diffgeom::typenum::consts::{U0, U1, U3};
+use diffgeom::{inner, mul};
+use generic_array::arr;
+
+struct Test3;
+impl CoordinateSystem for Test3 {
+    type Dimension = U3;
+}
+
+#[test]
+fn core_algebra() {
+    let p = Point::<Test3>::new(arr![f64; 0.0, 1.0, 2.0]);
+    let v = Vector::<Test3>::new(p, arr![f64; 1.0, 2.0, 3.0]);
+    let w = Covector::<Test3>::new(p, arr![f64; 2.0, 0.0, -1.0]);
+
+    let sum = v + mul!(Vector<Test3>, f64; v, 2.0);
+    assert_eq!(*sum.coords_array(), arr![f64; 3.0, 6.0, 9.0]);
+
+    let m = mul!(Vector<Test3>, Covector<Test3>; v, w);
+    assert_eq!(*m.trace::<U0, U1>(), -1.0);
+    assert_eq!(*inner!(Vector<Test3>, Covector<Test3>; U0, U1; v, w), -1.0);
+
+    let a = Matrix::<Test3>::new(p, arr![f64; 2.0, 0.0, 0.0, 0.0, 4.0, 0.0, 1.0, 0.0, 1.0]);
+    assert_eq!(a.determinant(), 8.0);
+    let inv = a.inverse().unwrap();
+    let expected = [0.5, 0.0, 0.0, 0.0, 0.25, 0.0, -0.5, 0.0, 1.0];
+    for (x, y) in inv.coords_array().iter().zip(expected.iter()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+}